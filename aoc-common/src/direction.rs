@@ -0,0 +1,96 @@
+use crate::point::Vector;
+
+/// A compass direction on a grid, where north is up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// The four orthogonal directions, clockwise from north.
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// All eight directions, clockwise from north.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    pub fn vector(self) -> Vector {
+        match self {
+            Direction::North => Vector::new(0, -1),
+            Direction::NorthEast => Vector::new(1, -1),
+            Direction::East => Vector::new(1, 0),
+            Direction::SouthEast => Vector::new(1, 1),
+            Direction::South => Vector::new(0, 1),
+            Direction::SouthWest => Vector::new(-1, 1),
+            Direction::West => Vector::new(-1, 0),
+            Direction::NorthWest => Vector::new(-1, -1),
+        }
+    }
+
+    /// Rotates a quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// Rotates a quarter turn anticlockwise.
+    pub fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    pub fn reverse(self) -> Self {
+        self.rotate(4)
+    }
+
+    fn rotate(self, eighths: usize) -> Self {
+        Self::ALL[(self as usize + eighths) % Self::ALL.len()]
+    }
+}
+
+/// Parses the arrows `^`, `>`, `v` and `<`.
+impl TryFrom<char> for Direction {
+    type Error = char;
+
+    fn try_from(arrow: char) -> Result<Self, Self::Error> {
+        match arrow {
+            '^' => Ok(Direction::North),
+            '>' => Ok(Direction::East),
+            'v' => Ok(Direction::South),
+            '<' => Ok(Direction::West),
+            _ => Err(arrow),
+        }
+    }
+}
+
+#[test]
+fn test_turns() {
+    assert_eq!(Direction::North.turn_right(), Direction::East);
+    assert_eq!(Direction::North.turn_left(), Direction::West);
+    assert_eq!(Direction::SouthWest.turn_right(), Direction::NorthWest);
+    assert_eq!(Direction::NorthEast.reverse(), Direction::SouthWest);
+}
+
+#[test]
+fn test_from_arrow() {
+    assert_eq!(Direction::try_from('v'), Ok(Direction::South));
+    assert_eq!(Direction::try_from('x'), Err('x'));
+}
@@ -0,0 +1,130 @@
+use std::{error::Error, fmt, io};
+
+use crate::{input::Source, Part};
+
+/// Returned by [`Solution::parse`](crate::Solution::parse) when the puzzle
+/// input is not in the expected shape.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub location: Option<Location>,
+    pub text: String,
+    pub reason: String,
+}
+
+/// 1-based line and column (in characters) of the offending text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl ParseError {
+    pub fn new(
+        day: u8,
+        location: Location,
+        text: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self {
+            day,
+            location: Some(location),
+            text: text.into(),
+            reason: reason.into(),
+        }
+    }
+
+    /// An error about the input as a whole, such as a missing section.
+    pub fn whole_input(day: u8, reason: impl Into<String>) -> Self {
+        Self {
+            day,
+            location: None,
+            text: String::new(),
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {}", self.day)?;
+        if let Some(Location { line, column }) = self.location {
+            write!(f, ", line {line}, column {column}")?;
+        }
+        write!(f, ": {}", self.reason)?;
+        if !self.text.is_empty() {
+            write!(f, " `{}`", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+#[test]
+fn test_display_with_location() {
+    let err = ParseError::new(1, Location { line: 3, column: 5 }, "12a", "invalid number");
+    assert_eq!(
+        err.to_string(),
+        "day 1, line 3, column 5: invalid number `12a`"
+    );
+}
+
+#[test]
+fn test_display_whole_input() {
+    let err = ParseError::whole_input(6, "no guard found in map");
+    assert_eq!(err.to_string(), "day 6: no guard found in map");
+}
+
+/// Returned by [`Solution::part1`](crate::Solution::part1) and
+/// [`Solution::part2`](crate::Solution::part2) when a parsed input has no
+/// answer, such as when it overflows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError {
+    pub day: u8,
+    pub part: Part,
+    pub reason: String,
+}
+
+impl SolveError {
+    pub fn new(day: u8, part: Part, reason: impl Into<String>) -> Self {
+        Self {
+            day,
+            part,
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {} part {}: {}", self.day, self.part, self.reason)
+    }
+}
+
+impl Error for SolveError {}
+
+#[test]
+fn test_display_solve_error() {
+    let err = SolveError::new(3, Part::Two, "accumulator overflowed");
+    assert_eq!(err.to_string(), "day 3 part 2: accumulator overflowed");
+}
+
+/// Returned when a puzzle input cannot be read.
+#[derive(Debug)]
+pub struct InputError {
+    pub input: Source,
+    pub error: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unable to read {}: {}", self.input, self.error)
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
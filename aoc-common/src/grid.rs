@@ -0,0 +1,282 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    parse::{lines, Line},
+    Direction, ParseError, Point, Vector,
+};
+
+/// A rectangular grid stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its cells in row-major order.
+    ///
+    /// Panics if `cells` does not hold exactly `width * height` cells.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "grid size mismatch");
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Builds a grid from nested rows.
+    ///
+    /// Panics if the rows are not all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "grid rows differ in length"
+        );
+        Self::new(width, height, rows.into_iter().flatten().collect())
+    }
+
+    /// Parses one cell per character, one row per line. Every row must be
+    /// as wide as the first.
+    pub fn parse(
+        day: u8,
+        input: &str,
+        mut cell: impl FnMut(&Line, usize, char) -> Result<T, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for line in lines(day, input) {
+            let row_start = cells.len();
+            for (offset, c) in line.text.char_indices() {
+                cells.push(cell(&line, offset, c)?);
+            }
+
+            let row_width = cells.len() - row_start;
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(line.error_at_end(format!(
+                        "expected a row of {width} cells, found {row_width}"
+                    )))
+                }
+                Some(_) => (),
+            }
+            height += 1;
+        }
+
+        Ok(Self::new(width.unwrap_or(0), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|idx| &mut self.cells[idx])
+    }
+
+    /// Moves `point` by `vector`, returning `None` if that leaves the grid.
+    pub fn offset(&self, point: Point, vector: Vector) -> Option<Point> {
+        point
+            .checked_add(vector)
+            .filter(|&point| self.contains(point))
+    }
+
+    /// The orthogonal neighbours of `point` inside the grid, clockwise from
+    /// the one above.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::CARDINAL
+            .into_iter()
+            .filter_map(move |direction| self.offset(point, direction.vector()))
+    }
+
+    /// The orthogonal and diagonal neighbours of `point` inside the grid,
+    /// clockwise from the one above.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.offset(point, direction.vector()))
+    }
+
+    /// Every point of the grid in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |idx| Point::new(idx % width, idx / width))
+    }
+
+    /// Every cell of the grid with its position, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &T> {
+        self.rows().nth(y).into_iter().flatten()
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.ray(Point::new(x, 0), Direction::South.vector())
+            .map(|point| &self[point])
+    }
+
+    /// The points from `start` onwards in steps of `step` until the edge of
+    /// the grid. Empty if `start` is outside the grid.
+    pub fn ray(&self, start: Point, step: Vector) -> impl Iterator<Item = Point> + '_ {
+        std::iter::successors(
+            Some(start).filter(|&start| self.contains(start)),
+            move |&point| self.offset(point, step),
+        )
+    }
+
+    /// Every diagonal running down and to the right, as a sequence of points.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = Point> + '_> {
+        let starts = (0..self.width)
+            .rev()
+            .map(|x| Point::new(x, 0))
+            .chain((1..self.height).map(|y| Point::new(0, y)));
+        starts.map(|start| self.ray(start, Direction::SouthEast.vector()))
+    }
+
+    /// Every diagonal running down and to the left, as a sequence of points.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = Point> + '_> {
+        let last_column = self.width.saturating_sub(1);
+        let starts = (0..self.width)
+            .map(|x| Point::new(x, 0))
+            .chain((1..self.height).map(move |y| Point::new(last_column, y)));
+        starts.map(|start| self.ray(start, Direction::SouthWest.vector()))
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(Point, &T) -> U) -> Grid<U> {
+        Grid::new(
+            self.width,
+            self.height,
+            self.iter().map(|(point, cell)| f(point, cell)).collect(),
+        )
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        self.contains(point)
+            .then_some(point.y * self.width + point.x)
+    }
+}
+
+impl Grid<char> {
+    /// Parses a grid of characters, accepting any character.
+    pub fn parse_chars(day: u8, input: &str) -> Result<Self, ParseError> {
+        Self::parse(day, input, |_, _, c| Ok(c))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_parse_and_display() {
+    let grid = Grid::parse_chars(4, "ab\ncd\nef\n").unwrap();
+
+    assert_eq!(grid.width(), 2);
+    assert_eq!(grid.height(), 3);
+    assert_eq!(grid[Point::new(1, 2)], 'f');
+    assert_eq!(grid.to_string(), "ab\ncd\nef");
+}
+
+#[test]
+fn test_parse_ragged_rows() {
+    let err = Grid::parse_chars(4, "abc\nab\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "day 4, line 2, column 3: expected a row of 3 cells, found 2 `ab`"
+    );
+}
+
+#[test]
+fn test_offset() {
+    let grid = Grid::from_rows(vec![vec![0; 3]; 2]);
+
+    assert_eq!(
+        grid.offset(Point::new(1, 1), Vector::new(1, -1)),
+        Some(Point::new(2, 0))
+    );
+    assert_eq!(grid.offset(Point::new(0, 0), Vector::new(-1, 0)), None);
+    assert_eq!(grid.offset(Point::new(2, 1), Vector::new(1, 0)), None);
+}
+
+#[test]
+fn test_neighbours() {
+    let grid = Grid::from_rows(vec![vec![0; 3]; 3]);
+
+    let corner: Vec<Point> = grid.neighbours4(Point::new(0, 0)).collect();
+    assert_eq!(corner, vec![Point::new(1, 0), Point::new(0, 1)]);
+    assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 8);
+    assert_eq!(grid.neighbours8(Point::new(2, 2)).count(), 3);
+}
+
+#[test]
+fn test_lines() {
+    let grid = Grid::parse_chars(4, "abc\ndef").unwrap();
+
+    assert_eq!(grid.row(1).collect::<String>(), "def");
+    assert_eq!(grid.column(2).collect::<String>(), "cf");
+
+    let diagonals: Vec<String> = grid
+        .diagonals()
+        .map(|diagonal| diagonal.map(|point| grid[point]).collect())
+        .collect();
+    assert_eq!(diagonals, vec!["c", "bf", "ae", "d"]);
+
+    let anti_diagonals: Vec<String> = grid
+        .anti_diagonals()
+        .map(|diagonal| diagonal.map(|point| grid[point]).collect())
+        .collect();
+    assert_eq!(anti_diagonals, vec!["a", "bd", "ce", "f"]);
+}
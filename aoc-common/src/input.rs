@@ -0,0 +1,171 @@
+use std::{
+    fmt,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::error::InputError;
+
+/// Directory holding the saved inputs, laid out as `dayNN/example.txt` and
+/// `dayNN/real.txt`.
+pub const INPUTS_DIR: &str = "inputs";
+
+/// Reads the whole of stdin into a string.
+pub fn read_stdin() -> io::Result<String> {
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input)?;
+    Ok(input)
+}
+
+/// Which of a day's saved inputs to use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputKind {
+    Example,
+    Real,
+}
+
+impl InputKind {
+    pub const ALL: [InputKind; 2] = [InputKind::Example, InputKind::Real];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            InputKind::Example => "example",
+            InputKind::Real => "real",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.name() == name)
+    }
+}
+
+/// Path of the saved input of `kind` for `day` under `dir`.
+pub fn saved_path(dir: &Path, day: u8, kind: InputKind) -> PathBuf {
+    dir.join(format!("day{day:02}"))
+        .join(format!("{}.txt", kind.name()))
+}
+
+/// Where a puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    Path(PathBuf),
+}
+
+impl Source {
+    /// Interprets an `--input` value, where `-` means stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Source::Stdin,
+            path => Source::Path(PathBuf::from(path)),
+        }
+    }
+
+    /// The saved input of `kind` for `day` under `dir`.
+    pub fn saved(dir: &Path, day: u8, kind: InputKind) -> Self {
+        Source::Path(saved_path(dir, day, kind))
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        let result = match self {
+            Source::Stdin => read_stdin(),
+            Source::Path(path) => fs::read_to_string(path),
+        };
+        result.map_err(|error| InputError {
+            input: self.clone(),
+            error,
+        })
+    }
+
+    /// Opens the input for streaming, for inputs too large to read whole.
+    pub fn open(&self) -> Result<Box<dyn BufRead>, InputError> {
+        match self {
+            Source::Stdin => Ok(Box::new(io::stdin().lock())),
+            Source::Path(path) => File::open(path)
+                .map(|file| Box::new(BufReader::new(file)) as Box<dyn BufRead>)
+                .map_err(|error| InputError {
+                    input: self.clone(),
+                    error,
+                }),
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "stdin"),
+            Source::Path(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// The `--input <PATH>` and `--example` flags shared by every binary.
+#[derive(Debug, Default)]
+pub struct InputArgs {
+    pub input: Option<Source>,
+    pub example: bool,
+}
+
+impl InputArgs {
+    /// Records `arg` if it is one of the input flags, taking its value from
+    /// `rest`. Returns whether `arg` was consumed.
+    pub fn accept<'a>(
+        &mut self,
+        arg: &str,
+        rest: &mut impl Iterator<Item = &'a String>,
+    ) -> Result<bool, String> {
+        match arg {
+            "--input" => {
+                let value = rest
+                    .next()
+                    .ok_or_else(|| format!("Missing value for `{arg}`"))?;
+                self.input = Some(Source::from_arg(value));
+            }
+            "--example" => self.example = true,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// The selected source for `day`, defaulting to stdin.
+    pub fn source(self, dir: &Path, day: u8) -> Result<Source, String> {
+        match (self.input, self.example) {
+            (Some(_), true) => Err("`--input` cannot be combined with `--example`".to_string()),
+            (Some(source), false) => Ok(source),
+            (None, true) => Ok(Source::saved(dir, day, InputKind::Example)),
+            (None, false) => Ok(Source::Stdin),
+        }
+    }
+}
+
+#[test]
+fn test_saved_path() {
+    let source = Source::saved(Path::new(INPUTS_DIR), 6, InputKind::Example);
+    assert_eq!(source.to_string(), "inputs/day06/example.txt");
+}
+
+#[test]
+fn test_input_args() {
+    let args = ["--input".to_string(), "-".to_string()];
+    let mut rest = args.iter();
+    let mut input_args = InputArgs::default();
+
+    let flag = rest.next().unwrap();
+    assert_eq!(input_args.accept(flag, &mut rest), Ok(true));
+    assert_eq!(input_args.accept("--part", &mut rest), Ok(false));
+    assert_eq!(
+        input_args.source(Path::new(INPUTS_DIR), 1),
+        Ok(Source::Stdin)
+    );
+}
+
+#[test]
+fn test_read_missing_file() {
+    let source = Source::Path(PathBuf::from("no/such/input.txt"));
+    let err = source.read().unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("unable to read no/such/input.txt: "));
+}
@@ -0,0 +1,18 @@
+//! Shared helpers for the Advent of Code 2024 solutions.
+
+pub mod direction;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod json;
+pub mod parse;
+pub mod point;
+pub mod random;
+pub mod runner;
+pub mod solution;
+
+pub use direction::Direction;
+pub use error::{InputError, ParseError, SolveError};
+pub use grid::Grid;
+pub use point::{Point, Vector};
+pub use solution::{Answer, Part, Solution};
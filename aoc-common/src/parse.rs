@@ -0,0 +1,91 @@
+use std::str::FromStr;
+
+use crate::{error::Location, ParseError};
+
+/// A line of puzzle input, used to point parse errors at the exact text that
+/// caused them.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub day: u8,
+    /// 1-based line number.
+    pub number: usize,
+    pub text: &'a str,
+}
+
+/// Splits `input` into [`Line`]s tagged with their line numbers.
+pub fn lines(day: u8, input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(move |(idx, text)| Line {
+        day,
+        number: idx + 1,
+        text,
+    })
+}
+
+impl<'a> Line<'a> {
+    /// An error pointing at `fragment`, which must be a slice of this line.
+    pub fn error(&self, fragment: &str, reason: impl Into<String>) -> ParseError {
+        let offset = (fragment.as_ptr() as usize)
+            .checked_sub(self.text.as_ptr() as usize)
+            .filter(|&offset| offset <= self.text.len())
+            .unwrap_or(0);
+        self.error_at(offset, fragment, reason)
+    }
+
+    /// An error pointing at the byte offset `offset` of this line.
+    pub fn error_at(&self, offset: usize, text: &str, reason: impl Into<String>) -> ParseError {
+        let column = self.text[..offset].chars().count() + 1;
+        ParseError::new(
+            self.day,
+            Location {
+                line: self.number,
+                column,
+            },
+            text,
+            reason,
+        )
+    }
+
+    /// An error pointing just past the end of this line, for missing text.
+    pub fn error_at_end(&self, reason: impl Into<String>) -> ParseError {
+        self.error_at(self.text.len(), self.text, reason)
+    }
+
+    /// Parses `fragment`, a slice of this line, as a number.
+    pub fn number<T: FromStr>(&self, fragment: &str) -> Result<T, ParseError> {
+        fragment.parse::<T>().map_err(|_| {
+            if fragment.is_empty() {
+                self.error(fragment, "missing number")
+            } else {
+                self.error(fragment, "invalid number")
+            }
+        })
+    }
+
+    /// The character at byte offset `offset` as a decimal digit.
+    pub fn digit(&self, offset: usize, c: char) -> Result<u32, ParseError> {
+        c.to_digit(10)
+            .ok_or_else(|| self.error_at(offset, &c.to_string(), "expected a digit"))
+    }
+}
+
+#[test]
+fn test_number_error_location() {
+    let line = lines(1, "3   4\n4   x3\n").nth(1).unwrap();
+    let field = line.text.split_whitespace().nth(1).unwrap();
+    let err = line.number::<u32>(field).unwrap_err();
+
+    assert_eq!(err.location, Some(Location { line: 2, column: 5 }));
+    assert_eq!(err.text, "x3");
+}
+
+#[test]
+fn test_digit_error_location() {
+    let line = lines(9, "12a4").next().unwrap();
+    let err = line.digit(2, 'a').unwrap_err();
+
+    assert_eq!(err.location, Some(Location { line: 1, column: 3 }));
+    assert_eq!(
+        err.to_string(),
+        "day 9, line 1, column 3: expected a digit `a`"
+    );
+}
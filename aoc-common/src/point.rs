@@ -0,0 +1,83 @@
+use std::ops::{Mul, Neg, Sub};
+
+/// A cell position in a [`Grid`](crate::grid::Grid), with `y` growing down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+/// A signed step between two [`Point`]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Vector {
+    pub dx: isize,
+    pub dy: isize,
+}
+
+impl Point {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// Moves by `vector`, returning `None` if either coordinate would go
+    /// negative or overflow.
+    pub fn checked_add(self, vector: Vector) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add_signed(vector.dx)?,
+            self.y.checked_add_signed(vector.dy)?,
+        ))
+    }
+}
+
+impl Vector {
+    pub const fn new(dx: isize, dy: isize) -> Self {
+        Self { dx, dy }
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Point) -> Vector {
+        let signed = |value: usize| isize::try_from(value).expect("coordinate overflows isize");
+        Vector::new(
+            signed(self.x) - signed(other.x),
+            signed(self.y) - signed(other.y),
+        )
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.dx, -self.dy)
+    }
+}
+
+impl Mul<isize> for Vector {
+    type Output = Vector;
+
+    fn mul(self, factor: isize) -> Vector {
+        Vector::new(self.dx * factor, self.dy * factor)
+    }
+}
+
+#[test]
+fn test_checked_add() {
+    let point = Point::new(2, 1);
+
+    assert_eq!(
+        point.checked_add(Vector::new(-2, 3)),
+        Some(Point::new(0, 4))
+    );
+    assert_eq!(point.checked_add(Vector::new(0, -2)), None);
+}
+
+#[test]
+fn test_difference() {
+    let vector = Point::new(1, 4) - Point::new(3, 2);
+
+    assert_eq!(vector, Vector::new(-2, 2));
+    assert_eq!(-vector * 2, Vector::new(4, -4));
+}
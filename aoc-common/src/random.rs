@@ -0,0 +1,37 @@
+//! Deterministic pseudo-random numbers, for tests that need varied input
+//! without extra dependencies.
+
+/// A linear congruential generator using Knuth's MMIX constants. The same
+/// seed always gives the same sequence; it is not meant for anything else.
+#[derive(Debug, Clone)]
+pub struct Lcg(u64);
+
+impl Lcg {
+    pub const fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    /// The next number, below `bound`.
+    pub fn below(&mut self, bound: u32) -> u32 {
+        self.0 = self
+            .0
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1);
+        // The low bits of an LCG cycle quickly, so only the top 31 are used.
+        u32::try_from(self.0 >> 33).unwrap() % bound
+    }
+}
+
+#[test]
+fn test_repeatable() {
+    let mut first = Lcg::new(0x2024);
+    let mut second = Lcg::new(0x2024);
+    let numbers: Vec<u32> = (0..100).map(|_| first.below(10)).collect();
+
+    assert!(numbers.iter().all(|&number| number < 10));
+    assert!((0..10).all(|number| numbers.contains(&number)));
+    assert_eq!(
+        numbers,
+        (0..100).map(|_| second.below(10)).collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,256 @@
+use std::{
+    path::Path,
+    process::ExitCode,
+    slice,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    input::{InputArgs, Source, INPUTS_DIR},
+    Answer, ParseError, Part, Solution, SolveError,
+};
+
+/// Answers and timings from parsing an input once and solving some parts.
+pub struct Run {
+    pub parse: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+pub struct PartRun {
+    pub part: Part,
+    pub answer: Result<Answer, SolveError>,
+    pub elapsed: Duration,
+}
+
+pub fn run<S: Solution>(input: &str, parts: &[Part]) -> Result<Run, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = S::solve(&parsed, part);
+            PartRun {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect();
+
+    Ok(Run { parse, parts })
+}
+
+/// The command-line arguments of a day binary, with `--input` and
+/// `--example` picked out as they go by.
+pub struct Args<'a> {
+    args: slice::Iter<'a, String>,
+    input_args: InputArgs,
+}
+
+impl<'a> Args<'a> {
+    pub fn new(args: &'a [String]) -> Self {
+        Self {
+            args: args.iter(),
+            input_args: InputArgs::default(),
+        }
+    }
+
+    /// The next argument that does not select the input.
+    pub fn next_arg(&mut self) -> Result<Option<&'a str>, String> {
+        while let Some(arg) = self.args.next() {
+            if !self.input_args.accept(arg, &mut self.args)? {
+                return Ok(Some(arg));
+            }
+        }
+        Ok(None)
+    }
+
+    /// The value following `flag`.
+    pub fn value(&mut self, flag: &str) -> Result<&'a str, String> {
+        self.args
+            .next()
+            .map(String::as_str)
+            .ok_or_else(|| format!("Missing value for `{flag}`"))
+    }
+
+    fn source(self, day: u8) -> Result<Source, String> {
+        self.input_args.source(Path::new(INPUTS_DIR), day)
+    }
+}
+
+/// Fails if more than one of the `(flag, given)` pairs was given.
+pub fn exclusive(flags: &[(&str, bool)]) -> Result<(), String> {
+    let mut given = flags.iter().filter(|(_, given)| *given);
+    match (given.next(), given.next()) {
+        (Some((first, _)), Some((second, _))) => {
+            Err(format!("`{first}` cannot be combined with `{second}`"))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Fails if `option` was given without the `flag` it belongs to.
+pub fn requires(option: (&str, bool), flag: (&str, bool)) -> Result<(), String> {
+    match (option, flag) {
+        ((option, true), (flag, false)) => Err(format!("`{option}` requires `{flag}`")),
+        _ => Ok(()),
+    }
+}
+
+/// What a day binary can do besides solving both parts, such as
+/// `day03 --trace`.
+pub trait Modes: Sized {
+    /// The usage text shown when the arguments are not understood.
+    fn usage(day: u8) -> String {
+        format!(
+            "Usage: day{day:02} [--input <PATH> | --example]\n\n\
+             Reads the puzzle input from <PATH> (`-` for stdin), from\n\
+             {INPUTS_DIR}/day{day:02}/example.txt with --example, or from stdin."
+        )
+    }
+
+    /// Reads every argument from `args`, returning `None` to solve both
+    /// parts.
+    fn parse(args: &mut Args<'_>) -> Result<Option<Self>, String>;
+
+    fn run(self, source: &Source) -> Result<(), String>;
+}
+
+/// A binary that only solves both parts.
+pub enum Solve {}
+
+impl Modes for Solve {
+    fn parse(args: &mut Args<'_>) -> Result<Option<Self>, String> {
+        match args.next_arg()? {
+            Some(arg) => Err(format!("Unknown argument `{arg}`")),
+            None => Ok(None),
+        }
+    }
+
+    fn run(self, _: &Source) -> Result<(), String> {
+        match self {}
+    }
+}
+
+/// Entry point for the per-day binaries: solves both parts of the input
+/// selected on the command line, or of stdin.
+pub fn main<S: Solution>() -> ExitCode {
+    main_with::<S, Solve>()
+}
+
+/// Like [`main`], but the arguments may instead select one of the modes `M`.
+/// Usage mistakes exit with code 2, any other error with code 1.
+pub fn main_with<S: Solution, M: Modes>() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (mode, source) = match parse_args::<M>(S::DAY, &args) {
+        Ok(parsed) => parsed,
+        Err(message) => {
+            eprintln!("{message}\n\n{}", M::usage(S::DAY));
+            return ExitCode::from(2);
+        }
+    };
+
+    let result = match mode {
+        Some(mode) => mode.run(&source),
+        None => solve::<S>(&source),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_args<M: Modes>(day: u8, args: &[String]) -> Result<(Option<M>, Source), String> {
+    let mut args = Args::new(args);
+    let mode = M::parse(&mut args)?;
+    if let Some(arg) = args.next_arg()? {
+        return Err(format!("Unknown argument `{arg}`"));
+    }
+    Ok((mode, args.source(day)?))
+}
+
+fn solve<S: Solution>(source: &Source) -> Result<(), String> {
+    let input = source.read().map_err(|err| err.to_string())?;
+    let run = run::<S>(&input, &Part::ALL).map_err(|err| err.to_string())?;
+    for part in run.parts {
+        let answer = part.answer.map_err(|err| err.to_string())?;
+        println!("Part {}: {answer}", part.part);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_args_skip_input_flags() {
+    let args: Vec<String> = ["--example", "--trace", "--input", "-", "--chunk-size", "64"]
+        .map(String::from)
+        .to_vec();
+    let mut args = Args::new(&args);
+
+    assert_eq!(args.next_arg(), Ok(Some("--trace")));
+    assert_eq!(args.next_arg(), Ok(Some("--chunk-size")));
+    assert_eq!(args.value("--chunk-size"), Ok("64"));
+    assert_eq!(args.next_arg(), Ok(None));
+    assert_eq!(
+        args.source(1),
+        Err("`--input` cannot be combined with `--example`".to_string())
+    );
+}
+
+#[test]
+fn test_solve_rejects_arguments() {
+    let args = ["--example".to_string(), "--trace".to_string()];
+    let err = parse_args::<Solve>(1, &args).map(|_| ()).unwrap_err();
+    assert_eq!(err, "Unknown argument `--trace`");
+}
+
+#[test]
+fn test_flag_checks() {
+    assert_eq!(exclusive(&[("--a", false), ("--b", true)]), Ok(()));
+    assert_eq!(
+        exclusive(&[("--a", true), ("--b", false), ("--c", true)]),
+        Err("`--a` cannot be combined with `--c`".to_string())
+    );
+    assert_eq!(requires(("--json", false), ("--explain", false)), Ok(()));
+    assert_eq!(
+        requires(("--json", true), ("--explain", false)),
+        Err("`--json` requires `--explain`".to_string())
+    );
+}
+
+#[test]
+fn test_run_keeps_part_errors() {
+    struct Failing;
+
+    impl Solution for Failing {
+        const DAY: u8 = 1;
+        type Parsed = ();
+
+        fn parse(_: &str) -> Result<(), ParseError> {
+            Ok(())
+        }
+
+        fn part1(_: &()) -> Result<Answer, SolveError> {
+            Ok(Answer::Number(1))
+        }
+
+        fn part2(_: &()) -> Result<Answer, SolveError> {
+            Err(SolveError::new(1, Part::Two, "overflow"))
+        }
+    }
+
+    let run = run::<Failing>("", &Part::ALL).unwrap();
+    let answers: Vec<_> = run.parts.into_iter().map(|part| part.answer).collect();
+    assert_eq!(
+        answers,
+        [
+            Ok(Answer::Number(1)),
+            Err(SolveError::new(1, Part::Two, "overflow"))
+        ]
+    );
+}
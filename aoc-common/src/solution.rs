@@ -0,0 +1,94 @@
+use std::fmt;
+
+use crate::{ParseError, SolveError};
+
+/// A single day's puzzle. The input is parsed once and the parsed form is
+/// shared by both parts.
+pub trait Solution {
+    const DAY: u8;
+
+    type Parsed;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError>;
+
+    fn part1(parsed: &Self::Parsed) -> Result<Answer, SolveError>;
+
+    fn part2(parsed: &Self::Parsed) -> Result<Answer, SolveError>;
+
+    fn solve(parsed: &Self::Parsed, part: Part) -> Result<Answer, SolveError> {
+        match part {
+            Part::One => Self::part1(parsed),
+            Part::Two => Self::part2(parsed),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl TryFrom<u8> for Part {
+    type Error = u8;
+
+    fn try_from(part: u8) -> Result<Self, Self::Error> {
+        match part {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(part),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(u64),
+    Text(String),
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Number(u64::from(value))
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Number(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Number(u64::try_from(value).unwrap())
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(value) => write!(f, "{value}"),
+            Answer::Text(value) => write!(f, "{value}"),
+        }
+    }
+}
@@ -0,0 +1,257 @@
+//! Times parsing and both parts of every day on its saved input.
+//!
+//! Run with `cargo bench -p aoc -- [--day <DAY>] [--example] [--time <SECS>]
+//! [--json <PATH>]`. Each day uses `inputs/dayNN/real.txt`, falling back to
+//! `example.txt` when there is no real input (or always with `--example`).
+//! A summary table is printed and the raw statistics are written as JSON,
+//! by default to `target/bench/solutions.json`.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use aoc::days::{Day, DAYS};
+use aoc_common::{
+    input::{saved_path, InputKind, Source},
+    json::Json,
+    Part,
+};
+
+const WARM_UP: Duration = Duration::from_millis(100);
+const MIN_SAMPLES: usize = 5;
+const MAX_SAMPLES: usize = 10_000;
+
+fn workspace() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the aoc crate lives inside the workspace")
+}
+
+struct Options {
+    day: Option<u8>,
+    example: bool,
+    time: Duration,
+    json: PathBuf,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut options = Options {
+        day: None,
+        example: false,
+        time: Duration::from_secs(1),
+        json: workspace().join("target/bench/solutions.json"),
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for `{arg}`"))
+        };
+        match arg.as_str() {
+            // Passed through by `cargo bench`.
+            "--bench" => (),
+            "--day" => {
+                let value = value()?;
+                let day = value
+                    .parse()
+                    .map_err(|_| format!("Invalid day `{value}`"))?;
+                options.day = Some(day);
+            }
+            "--example" => options.example = true,
+            "--time" => {
+                let value = value()?;
+                let secs = value
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|secs| secs.is_finite() && *secs > 0.0)
+                    .ok_or_else(|| format!("Invalid time `{value}`"))?;
+                options.time = Duration::from_secs_f64(secs);
+            }
+            "--json" => options.json = PathBuf::from(value()?),
+            other => return Err(format!("Unknown argument `{other}`")),
+        }
+    }
+    Ok(options)
+}
+
+/// Summary of the timings of one stage across every sample.
+struct Stats {
+    min: Duration,
+    median: Duration,
+    mean: Duration,
+    max: Duration,
+}
+
+impl Stats {
+    fn new(samples: &mut [Duration]) -> Self {
+        samples.sort_unstable();
+        let total: Duration = samples.iter().sum();
+        Self {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            mean: total / u32::try_from(samples.len()).unwrap(),
+            max: samples[samples.len() - 1],
+        }
+    }
+
+    fn to_json(&self) -> Json {
+        Json::object([
+            ("min_ns", self.min.as_nanos().into()),
+            ("median_ns", self.median.as_nanos().into()),
+            ("mean_ns", self.mean.as_nanos().into()),
+            ("max_ns", self.max.as_nanos().into()),
+        ])
+    }
+}
+
+struct DayBench {
+    day: u8,
+    kind: InputKind,
+    samples: usize,
+    stages: Vec<(&'static str, Stats)>,
+}
+
+fn pick_input(day: u8, example: bool) -> Option<(InputKind, PathBuf)> {
+    let inputs = workspace().join("inputs");
+    let kinds: &[InputKind] = if example {
+        &[InputKind::Example]
+    } else {
+        &[InputKind::Real, InputKind::Example]
+    };
+    kinds
+        .iter()
+        .map(|&kind| (kind, saved_path(&inputs, day, kind)))
+        .find(|(_, path)| path.exists())
+}
+
+fn bench_day(day: &Day, kind: InputKind, input: &str, time: Duration) -> Result<DayBench, String> {
+    // Timing a part that fails would only time how quickly it gives up.
+    let run = || {
+        let run = (day.run)(input, &Part::ALL).map_err(|err| err.to_string())?;
+        if let Some(err) = run.parts.iter().find_map(|part| part.answer.as_ref().err()) {
+            return Err(err.to_string());
+        }
+        Ok(run)
+    };
+
+    let warm_up = Instant::now();
+    while warm_up.elapsed() < WARM_UP {
+        run()?;
+    }
+
+    let mut parse = Vec::new();
+    let mut parts: [Vec<Duration>; 2] = Default::default();
+    let start = Instant::now();
+    while parse.len() < MAX_SAMPLES && (parse.len() < MIN_SAMPLES || start.elapsed() < time) {
+        let sample = run()?;
+        parse.push(sample.parse);
+        for (timings, part) in parts.iter_mut().zip(&sample.parts) {
+            timings.push(part.elapsed);
+        }
+    }
+
+    let [mut part1, mut part2] = parts;
+    Ok(DayBench {
+        day: day.day,
+        kind,
+        samples: parse.len(),
+        stages: vec![
+            ("parse", Stats::new(&mut parse)),
+            ("part1", Stats::new(&mut part1)),
+            ("part2", Stats::new(&mut part2)),
+        ],
+    })
+}
+
+fn print_table(benches: &[DayBench]) {
+    println!(
+        "{:<5} {:<8} {:<6} {:>12} {:>12} {:>12} {:>8}",
+        "day", "input", "stage", "median", "mean", "min", "samples"
+    );
+    for bench in benches {
+        for (stage, stats) in &bench.stages {
+            println!(
+                "{:<5} {:<8} {:<6} {:>12} {:>12} {:>12} {:>8}",
+                format!("{:02}", bench.day),
+                bench.kind.name(),
+                stage,
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.mean),
+                format!("{:.2?}", stats.min),
+                bench.samples
+            );
+        }
+    }
+}
+
+fn to_json(benches: &[DayBench]) -> Json {
+    let days = benches
+        .iter()
+        .map(|bench| {
+            Json::object([
+                ("day", bench.day.into()),
+                ("input", bench.kind.name().into()),
+                ("samples", bench.samples.into()),
+                (
+                    "stages",
+                    Json::object(
+                        bench
+                            .stages
+                            .iter()
+                            .map(|(stage, stats)| (*stage, stats.to_json())),
+                    ),
+                ),
+            ])
+        })
+        .collect();
+    Json::object([("days", Json::Array(days))])
+}
+
+fn run(options: &Options) -> Result<(), String> {
+    let mut benches = Vec::new();
+    for day in DAYS
+        .iter()
+        .filter(|day| options.day.is_none_or(|only| only == day.day))
+    {
+        let Some((kind, path)) = pick_input(day.day, options.example) else {
+            eprintln!("Day {:02}: skipped, no saved input", day.day);
+            continue;
+        };
+        let input = Source::Path(path).read().map_err(|err| err.to_string())?;
+        benches.push(bench_day(day, kind, &input, options.time)?);
+    }
+
+    print_table(&benches);
+
+    if let Some(dir) = options.json.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|err| format!("Unable to create {}: {err}", dir.display()))?;
+    }
+    fs::write(&options.json, format!("{}\n", to_json(&benches)))
+        .map_err(|err| format!("Unable to write {}: {err}", options.json.display()))?;
+    println!("\nWrote {}", options.json.display());
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let options = match parse_args(&args) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(&options) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,153 @@
+//! The registry of known-correct answers, stored as a small subset of TOML:
+//!
+//! ```toml
+//! [day01.example]
+//! part1 = 11
+//! part2 = "31"
+//! ```
+//!
+//! Each table is named after a day and an input kind, and holds a `part1`
+//! and/or `part2` answer as an integer or a string.
+
+use std::{collections::HashMap, fs, path::Path};
+
+use aoc_common::{input::InputKind, Part};
+
+/// File name of the registry inside the inputs directory.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Debug, Default)]
+pub struct Answers {
+    expected: HashMap<(u8, InputKind, Part), String>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|err| format!("unable to read {}: {err}", path.display()))?;
+        Self::parse(&text).map_err(|err| format!("{}: {err}", path.display()))
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut answers = Self::default();
+        let mut table = None;
+
+        for (idx, line) in text.lines().enumerate() {
+            let line_error = |reason: String| format!("line {}: {reason}", idx + 1);
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[') {
+                let header = header
+                    .strip_suffix(']')
+                    .ok_or_else(|| line_error("unterminated table header".to_string()))?;
+                table = Some(parse_table(header.trim()).map_err(line_error)?);
+                continue;
+            }
+
+            let (day, kind) =
+                table.ok_or_else(|| line_error("answer outside of a table".to_string()))?;
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| line_error(format!("expected `key = value`, found `{line}`")))?;
+            let part = parse_part(key.trim()).map_err(line_error)?;
+            let value = parse_value(value.trim()).map_err(line_error)?;
+
+            if answers.expected.insert((day, kind, part), value).is_some() {
+                return Err(line_error(format!("duplicate answer for part {part}")));
+            }
+        }
+
+        Ok(answers)
+    }
+
+    pub fn get(&self, day: u8, kind: InputKind, part: Part) -> Option<&str> {
+        self.expected.get(&(day, kind, part)).map(String::as_str)
+    }
+}
+
+fn strip_comment(line: &str) -> &str {
+    // A `#` inside a quoted answer is part of the answer.
+    let mut quoted = false;
+    for (idx, c) in line.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            '#' if !quoted => return &line[..idx],
+            _ => (),
+        }
+    }
+    line
+}
+
+fn parse_table(header: &str) -> Result<(u8, InputKind), String> {
+    let invalid =
+        || format!("expected a `[dayNN.example]` or `[dayNN.real]` table, found `[{header}]`");
+    let (day, kind) = header.split_once('.').ok_or_else(invalid)?;
+    let day = day
+        .strip_prefix("day")
+        .and_then(|day| day.parse::<u8>().ok())
+        .ok_or_else(invalid)?;
+    let kind = InputKind::from_name(kind).ok_or_else(invalid)?;
+    Ok((day, kind))
+}
+
+fn parse_part(key: &str) -> Result<Part, String> {
+    key.strip_prefix("part")
+        .and_then(|part| part.parse::<u8>().ok())
+        .and_then(|part| Part::try_from(part).ok())
+        .ok_or_else(|| format!("expected `part1` or `part2`, found `{key}`"))
+}
+
+fn parse_value(value: &str) -> Result<String, String> {
+    if let Some(quoted) = value.strip_prefix('"') {
+        let text = quoted
+            .strip_suffix('"')
+            .ok_or_else(|| format!("unterminated string `{value}`"))?;
+        if text.contains(['"', '\\']) {
+            return Err(format!("escapes are not supported in `{value}`"));
+        }
+        Ok(text.to_string())
+    } else if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) {
+        Ok(value.to_string())
+    } else {
+        Err(format!(
+            "expected an integer or a quoted string, found `{value}`"
+        ))
+    }
+}
+
+#[test]
+fn test_parse_answers() {
+    let answers = Answers::parse(
+        "# Known answers\n\
+         [day01.example]\n\
+         part1 = 11\n\
+         part2 = \"31\" # comment\n\
+         \n\
+         [day03.real]\n\
+         part2 = \"a#b\"\n",
+    )
+    .unwrap();
+
+    assert_eq!(answers.get(1, InputKind::Example, Part::One), Some("11"));
+    assert_eq!(answers.get(1, InputKind::Example, Part::Two), Some("31"));
+    assert_eq!(answers.get(3, InputKind::Real, Part::Two), Some("a#b"));
+    assert_eq!(answers.get(3, InputKind::Real, Part::One), None);
+}
+
+#[test]
+fn test_parse_answers_errors() {
+    let err = Answers::parse("part1 = 1").unwrap_err();
+    assert_eq!(err, "line 1: answer outside of a table");
+
+    let err = Answers::parse("[day01.sample]").unwrap_err();
+    assert_eq!(
+        err,
+        "line 1: expected a `[dayNN.example]` or `[dayNN.real]` table, found `[day01.sample]`"
+    );
+
+    let err = Answers::parse("[day01.real]\npart1 = 1\npart1 = 2").unwrap_err();
+    assert_eq!(err, "line 3: duplicate answer for part 1");
+}
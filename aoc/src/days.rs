@@ -0,0 +1,45 @@
+use aoc_common::{
+    runner::{self, Run},
+    ParseError, Part, Solution,
+};
+use day01::Day01;
+use day02::Day02;
+use day03::Day03;
+use day04::Day04;
+use day05::Day05;
+use day06::Day06;
+use day07::Day07;
+use day08::Day08;
+use day09::Day09;
+use day10::Day10;
+
+pub struct Day {
+    pub day: u8,
+    pub run: fn(&str, &[Part]) -> Result<Run, ParseError>,
+}
+
+impl Day {
+    const fn of<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            run: runner::run::<S>,
+        }
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day::of::<Day01>(),
+    Day::of::<Day02>(),
+    Day::of::<Day03>(),
+    Day::of::<Day04>(),
+    Day::of::<Day05>(),
+    Day::of::<Day06>(),
+    Day::of::<Day07>(),
+    Day::of::<Day08>(),
+    Day::of::<Day09>(),
+    Day::of::<Day10>(),
+];
+
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|entry| entry.day == day)
+}
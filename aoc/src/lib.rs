@@ -0,0 +1,5 @@
+//! The registry of implemented days and of their known answers, shared by
+//! the `aoc` binary, its benchmarks and the example tests.
+
+pub mod answers;
+pub mod days;
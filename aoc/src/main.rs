@@ -0,0 +1,175 @@
+use std::{path::PathBuf, process::ExitCode};
+
+use aoc_common::{
+    input::{saved_path, InputArgs, InputKind, Source, INPUTS_DIR},
+    Part,
+};
+
+mod verify;
+
+use aoc::days::{self, Day, DAYS};
+
+const USAGE: &str = "\
+Usage:
+    aoc run --day <DAY> [--part <PART>] [--input <PATH> | --example] [--inputs <DIR>]
+    aoc run --all [--example] [--inputs <DIR>]
+    aoc verify [--day <DAY>] [--inputs <DIR>]
+
+A single day reads its puzzle input from <PATH> (`-` for stdin), from
+<DIR>/dayNN/example.txt with --example, or from stdin. With --all every
+implemented day is run against <DIR>/dayNN/real.txt, or example.txt with
+--example. <DIR> defaults to inputs.
+
+verify runs every saved input and checks the answers against
+<DIR>/answers.toml, exiting non-zero if any do not match.";
+
+enum Command {
+    Run {
+        day: u8,
+        part: Option<Part>,
+        input: Source,
+    },
+    RunAll {
+        inputs: PathBuf,
+        kind: InputKind,
+    },
+    Verify {
+        inputs: PathBuf,
+        day: Option<u8>,
+    },
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter();
+    let verify = match args.next().map(String::as_str) {
+        Some("run") => false,
+        Some("verify") => true,
+        Some(other) => return Err(format!("Unknown command `{other}`")),
+        None => return Err("Missing command".to_string()),
+    };
+
+    let mut day = None;
+    let mut part = None;
+    let mut input_args = InputArgs::default();
+    let mut inputs = None;
+    let mut all = false;
+
+    while let Some(arg) = args.next() {
+        if input_args.accept(arg, &mut args)? {
+            continue;
+        }
+
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for `{arg}`"))
+        };
+        match arg.as_str() {
+            "--day" => day = Some(parse_number(value()?, "day")?),
+            "--part" => part = Some(parse_part(value()?)?),
+            "--inputs" => inputs = Some(PathBuf::from(value()?)),
+            "--all" => all = true,
+            other => return Err(format!("Unknown argument `{other}`")),
+        }
+    }
+
+    let inputs = inputs.unwrap_or_else(|| PathBuf::from(INPUTS_DIR));
+    if verify {
+        if all || part.is_some() || input_args.input.is_some() || input_args.example {
+            return Err("`verify` only accepts `--day` and `--inputs`".to_string());
+        }
+        return Ok(Command::Verify { inputs, day });
+    }
+
+    match (all, day) {
+        (true, None) if part.is_none() && input_args.input.is_none() => Ok(Command::RunAll {
+            inputs,
+            kind: if input_args.example {
+                InputKind::Example
+            } else {
+                InputKind::Real
+            },
+        }),
+        (true, _) => {
+            Err("`--all` cannot be combined with `--day`, `--part` or `--input`".to_string())
+        }
+        (false, Some(day)) => Ok(Command::Run {
+            day,
+            part,
+            input: input_args.source(&inputs, day)?,
+        }),
+        (false, None) => Err("Either `--day` or `--all` is required".to_string()),
+    }
+}
+
+fn parse_number(value: &str, name: &str) -> Result<u8, String> {
+    value
+        .parse::<u8>()
+        .map_err(|_| format!("Invalid {name} `{value}`"))
+}
+
+fn parse_part(value: &str) -> Result<Part, String> {
+    parse_number(value, "part")
+        .and_then(|part| Part::try_from(part).map_err(|_| format!("Invalid part `{value}`")))
+}
+
+fn run_day(day: &Day, input: &str, parts: &[Part]) -> Result<(), String> {
+    let run = (day.run)(input, parts).map_err(|err| err.to_string())?;
+
+    println!("Day {:02} parsed in {:?}", day.day, run.parse);
+    for part in run.parts {
+        let answer = part.answer.map_err(|err| err.to_string())?;
+        println!(
+            "Day {:02} part {}: {answer} ({:?})",
+            day.day, part.part, part.elapsed
+        );
+    }
+    Ok(())
+}
+
+fn run(command: Command) -> Result<(), String> {
+    match command {
+        Command::Run { day, part, input } => {
+            let day = days::find(day).ok_or_else(|| format!("Day {day} is not implemented"))?;
+            let input = input.read().map_err(|err| err.to_string())?;
+            let parts = part.map_or(Part::ALL.to_vec(), |part| vec![part]);
+            run_day(day, &input, &parts)?;
+        }
+        Command::RunAll { inputs, kind } => {
+            for day in DAYS {
+                let path = saved_path(&inputs, day.day, kind);
+                if !path.exists() {
+                    println!(
+                        "Day {:02}: skipped, no input at {}",
+                        day.day,
+                        path.display()
+                    );
+                    continue;
+                }
+
+                let input = Source::Path(path).read().map_err(|err| err.to_string())?;
+                run_day(day, &input, &Part::ALL)?;
+            }
+        }
+        Command::Verify { inputs, day } => verify::verify(&inputs, day)?,
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let command = match parse_args(&args) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("{message}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(command) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use std::path::Path;
+
+use aoc::{
+    answers::{Answers, ANSWERS_FILE},
+    days::{self, Day, DAYS},
+};
+use aoc_common::{
+    input::{saved_path, InputKind, Source},
+    Part,
+};
+
+#[derive(Default)]
+struct Tally {
+    passed: usize,
+    failed: usize,
+    unchecked: usize,
+}
+
+/// Runs every saved input under `inputs` (or only those of `day`) and
+/// compares the answers against the registry, failing on any mismatch.
+pub fn verify(inputs: &Path, day: Option<u8>) -> Result<(), String> {
+    let answers = Answers::load(&inputs.join(ANSWERS_FILE))?;
+    let days: Vec<&Day> = match day {
+        Some(day) => vec![days::find(day).ok_or_else(|| format!("Day {day} is not implemented"))?],
+        None => DAYS.iter().collect(),
+    };
+
+    let mut tally = Tally::default();
+    for day in days {
+        for kind in InputKind::ALL {
+            verify_input(inputs, day, kind, &answers, &mut tally)?;
+        }
+    }
+
+    println!(
+        "{} passed, {} failed, {} unchecked",
+        tally.passed, tally.failed, tally.unchecked
+    );
+    if tally.failed > 0 {
+        return Err(format!(
+            "{} of {} checked answers failed",
+            tally.failed,
+            tally.passed + tally.failed
+        ));
+    }
+    Ok(())
+}
+
+fn verify_input(
+    inputs: &Path,
+    day: &Day,
+    kind: InputKind,
+    answers: &Answers,
+    tally: &mut Tally,
+) -> Result<(), String> {
+    let label = format!("Day {:02} {}", day.day, kind.name());
+    let path = saved_path(inputs, day.day, kind);
+    if !path.exists() {
+        println!("{label}: skipped, no input at {}", path.display());
+        return Ok(());
+    }
+
+    let input = Source::Path(path).read().map_err(|err| err.to_string())?;
+    let run = match (day.run)(&input, &Part::ALL) {
+        Ok(run) => run,
+        Err(err) => {
+            println!("{label}: FAIL {err}");
+            tally.failed += 1;
+            return Ok(());
+        }
+    };
+
+    for part in run.parts {
+        let elapsed = part.elapsed;
+        let answer = match part.answer {
+            Ok(answer) => answer.to_string(),
+            Err(err) => {
+                println!("{label} part {}: FAIL {err} ({elapsed:?})", part.part);
+                tally.failed += 1;
+                continue;
+            }
+        };
+        match answers.get(day.day, kind, part.part) {
+            Some(expected) if expected == answer => {
+                println!("{label} part {}: pass {answer} ({elapsed:?})", part.part);
+                tally.passed += 1;
+            }
+            Some(expected) => {
+                println!(
+                    "{label} part {}: MISMATCH got {answer}, expected {expected} ({elapsed:?})",
+                    part.part
+                );
+                tally.failed += 1;
+            }
+            None => {
+                println!(
+                    "{label} part {}: unchecked {answer}, no expected answer ({elapsed:?})",
+                    part.part
+                );
+                tally.unchecked += 1;
+            }
+        }
+    }
+    Ok(())
+}
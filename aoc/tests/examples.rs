@@ -0,0 +1,148 @@
+//! Runs every saved input under `inputs/` that has answers in
+//! `inputs/answers.toml`, plus the regression cases under
+//! `tests/examples/dayNN/`. Each `<name>.in` there is a puzzle input and the
+//! matching `<name>.out` lists the expected answers, one per line as
+//! `part1: <answer>` and/or `part2: <answer>`. Inputs that are already saved
+//! under `inputs/` belong there, not here.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aoc::{
+    answers::{Answers, ANSWERS_FILE},
+    days::{self, DAYS},
+};
+use aoc_common::{
+    input::{saved_path, InputKind},
+    Part,
+};
+
+struct Example {
+    day: u8,
+    input: PathBuf,
+    expected: Vec<(Part, String)>,
+}
+
+fn parse_expected(path: &Path) -> Vec<(Part, String)> {
+    let text = fs::read_to_string(path)
+        .unwrap_or_else(|err| panic!("unable to read {}: {err}", path.display()));
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let (key, answer) = line
+                .split_once(':')
+                .unwrap_or_else(|| panic!("{}: expected `partN: answer`", path.display()));
+            let part = match key.trim() {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                other => panic!("{}: unknown part `{other}`", path.display()),
+            };
+            (part, answer.trim().to_string())
+        })
+        .collect()
+}
+
+/// The saved inputs, with the answers recorded for them.
+fn saved() -> Vec<Example> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let inputs = root.join("inputs");
+    let answers = Answers::load(&inputs.join(ANSWERS_FILE)).unwrap();
+    let mut examples = Vec::new();
+
+    for day in DAYS {
+        for kind in InputKind::ALL {
+            let input = saved_path(&inputs, day.day, kind);
+            let expected: Vec<(Part, String)> = Part::ALL
+                .into_iter()
+                .filter_map(|part| {
+                    let answer = answers.get(day.day, kind, part)?;
+                    Some((part, answer.to_string()))
+                })
+                .collect();
+            if input.exists() && !expected.is_empty() {
+                examples.push(Example {
+                    day: day.day,
+                    input,
+                    expected,
+                });
+            }
+        }
+    }
+    examples
+}
+
+/// The regression cases under `tests/examples`.
+fn discover() -> Vec<Example> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/examples");
+    let mut examples = Vec::new();
+
+    for dir in fs::read_dir(&root).unwrap() {
+        let dir = dir.unwrap().path();
+        let name = dir.file_name().unwrap().to_string_lossy().into_owned();
+        let day = name
+            .strip_prefix("day")
+            .and_then(|day| day.parse().ok())
+            .unwrap_or_else(|| panic!("{}: expected a `dayNN` directory", dir.display()));
+
+        for file in fs::read_dir(&dir).unwrap() {
+            let input = file.unwrap().path();
+            if input.extension().is_some_and(|ext| ext == "in") {
+                let expected = parse_expected(&input.with_extension("out"));
+                examples.push(Example {
+                    day,
+                    input,
+                    expected,
+                });
+            }
+        }
+    }
+
+    examples.sort_by(|a, b| a.input.cmp(&b.input));
+    examples
+}
+
+#[test]
+fn examples() {
+    let mut examples = saved();
+    assert!(!examples.is_empty(), "no saved inputs with answers found");
+    examples.extend(discover());
+
+    let mut failures = Vec::new();
+    for example in &examples {
+        let name = example.input.display();
+        let Some(day) = days::find(example.day) else {
+            failures.push(format!("{name}: day {} is not implemented", example.day));
+            continue;
+        };
+
+        let input = fs::read_to_string(&example.input).unwrap();
+        let parts: Vec<Part> = example.expected.iter().map(|(part, _)| *part).collect();
+        let run = match (day.run)(&input, &parts) {
+            Ok(run) => run,
+            Err(err) => {
+                failures.push(format!("{name}: {err}"));
+                continue;
+            }
+        };
+
+        for (part_run, (part, expected)) in run.parts.iter().zip(&example.expected) {
+            match &part_run.answer {
+                Ok(answer) if answer.to_string() == *expected => {}
+                Ok(answer) => failures.push(format!(
+                    "{name} part {part}: got {answer}, expected {expected}"
+                )),
+                Err(err) => failures.push(format!("{name} part {part}: {err}")),
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} of {} examples failed:\n{}",
+        failures.len(),
+        examples.len(),
+        failures.join("\n")
+    );
+}
@@ -0,0 +1,229 @@
+//! Lists with any number of columns, compared pairwise with the part 1
+//! distance and the part 2 similarity score.
+
+use std::{collections::HashMap, fmt, iter::zip};
+
+use aoc_common::{
+    parse::{lines, Line},
+    ParseError, Solution,
+};
+
+use crate::Day01;
+
+/// How the fields of a row are separated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delimiter {
+    /// Any run of whitespace, as in the puzzle input.
+    Whitespace,
+    /// A single character such as `,` for CSV or `\t` for TSV.
+    Char(char),
+}
+
+impl Delimiter {
+    /// Parses `whitespace`, `csv`, `tsv` or a single delimiter character.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "whitespace" => Some(Delimiter::Whitespace),
+            "csv" => Some(Delimiter::Char(',')),
+            "tsv" => Some(Delimiter::Char('\t')),
+            _ => {
+                let mut chars = name.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Some(Delimiter::Char(c)),
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+/// Splits a row into its fields, each a slice of `text` so that errors can
+/// point at them.
+pub fn split_row(text: &str, delimiter: Delimiter) -> Vec<&str> {
+    match delimiter {
+        Delimiter::Whitespace => text.split_whitespace().collect(),
+        Delimiter::Char(c) => text.split(c).map(str::trim).collect(),
+    }
+}
+
+/// Parses a row of exactly `width` numbers.
+pub fn parse_row(line: &Line, delimiter: Delimiter, width: usize) -> Result<Vec<u32>, ParseError> {
+    let fields = split_row(line.text, delimiter);
+    if fields.len() != width {
+        return Err(column_count_error(line, &fields, width));
+    }
+    fields.iter().map(|field| line.number(field)).collect()
+}
+
+/// The columns of a list file, with a name for each.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Table {
+    pub headers: Vec<String>,
+    pub columns: Vec<Vec<u32>>,
+}
+
+impl Table {
+    /// Parses rows of numbers, all as wide as the first row. With `header`
+    /// the first line names the columns instead of holding numbers.
+    pub fn parse(input: &str, delimiter: Delimiter, header: bool) -> Result<Self, ParseError> {
+        let mut lines = lines(Day01::DAY, input);
+        let mut headers: Option<Vec<String>> = None;
+        if header {
+            let line = lines
+                .next()
+                .ok_or_else(|| ParseError::whole_input(Day01::DAY, "missing header row"))?;
+            headers = Some(
+                split_row(line.text, delimiter)
+                    .into_iter()
+                    .map(str::to_string)
+                    .collect(),
+            );
+        }
+
+        // Without a header the first row decides how many columns there are.
+        let mut columns: Vec<Vec<u32>> = vec![Vec::new(); headers.as_ref().map_or(0, Vec::len)];
+        let mut sized = headers.is_some();
+        for line in lines {
+            if !sized {
+                columns = vec![Vec::new(); split_row(line.text, delimiter).len()];
+                sized = true;
+            }
+
+            let row = parse_row(&line, delimiter, columns.len())?;
+            for (column, num) in columns.iter_mut().zip(row) {
+                column.push(num);
+            }
+        }
+
+        let headers =
+            headers.unwrap_or_else(|| (1..=columns.len()).map(|idx| format!("col{idx}")).collect());
+        Ok(Self { headers, columns })
+    }
+
+    /// `distance[i][j]` is the part 1 answer for columns `i` and `j`.
+    pub fn distance_matrix(&self) -> Matrix<'_> {
+        self.matrix(sorted_distance)
+    }
+
+    /// `similarity[i][j]` is the part 2 answer with column `i` on the left
+    /// and column `j` on the right.
+    pub fn similarity_matrix(&self) -> Matrix<'_> {
+        self.matrix(similarity)
+    }
+
+    fn matrix(&self, score: impl Fn(&[u32], &[u32]) -> u64) -> Matrix<'_> {
+        let cells = self
+            .columns
+            .iter()
+            .map(|a| self.columns.iter().map(|b| score(a, b)).collect())
+            .collect();
+        Matrix {
+            headers: &self.headers,
+            cells,
+        }
+    }
+}
+
+fn column_count_error(line: &Line, fields: &[&str], width: usize) -> ParseError {
+    match fields.get(width) {
+        Some(extra) => line.error(extra, format!("unexpected column {}", width + 1)),
+        None => line.error_at_end(format!("expected {width} numbers")),
+    }
+}
+
+/// A square table of scores between every pair of columns.
+pub struct Matrix<'a> {
+    pub headers: &'a [String],
+    pub cells: Vec<Vec<u64>>,
+}
+
+impl fmt::Display for Matrix<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .headers
+            .iter()
+            .map(|header| header.chars().count())
+            .chain(
+                self.cells
+                    .iter()
+                    .flatten()
+                    .map(|cell| cell.to_string().len()),
+            )
+            .max()
+            .unwrap_or(0);
+
+        write!(f, "{:width$}", "")?;
+        for header in self.headers {
+            write!(f, "  {header:>width$}")?;
+        }
+        writeln!(f)?;
+        for (header, row) in zip(self.headers, &self.cells) {
+            write!(f, "{header:<width$}")?;
+            for cell in row {
+                write!(f, "  {cell:>width$}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Total distance between the columns once both are sorted.
+pub fn sorted_distance(a: &[u32], b: &[u32]) -> u64 {
+    let mut a = a.to_vec();
+    let mut b = b.to_vec();
+    a.sort_unstable();
+    b.sort_unstable();
+
+    zip(a, b)
+        .map(|(a, b)| u64::from(a.abs_diff(b)))
+        .sum::<u64>()
+}
+
+/// Each ID in `a` multiplied by how often it appears in `b`, summed.
+pub fn similarity(a: &[u32], b: &[u32]) -> u64 {
+    let mut counts: HashMap<u32, u64> = HashMap::new();
+    b.iter()
+        .for_each(|&num| *counts.entry(num).or_default() += 1);
+
+    a.iter()
+        .map(|&num| u64::from(num) * counts.get(&num).copied().unwrap_or(0))
+        .sum::<u64>()
+}
+
+#[test]
+fn test_csv_with_header() {
+    let table = Table::parse("a, b, c\n3, 4, 1\n4, 3, 1\n", Delimiter::Char(','), true).unwrap();
+
+    assert_eq!(table.headers, vec!["a", "b", "c"]);
+    assert_eq!(table.columns, vec![vec![3, 4], vec![4, 3], vec![1, 1]]);
+}
+
+#[test]
+fn test_two_column_matrices() {
+    let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+    let table = Table::parse(input, Delimiter::Whitespace, false).unwrap();
+
+    assert_eq!(
+        table.distance_matrix().cells,
+        vec![vec![0, 11], vec![11, 0]]
+    );
+    assert_eq!(
+        table.similarity_matrix().cells[0][1],
+        31,
+        "left against right is the part 2 answer"
+    );
+    assert_eq!(
+        table.distance_matrix().to_string(),
+        "      col1  col2\ncol1     0    11\ncol2    11     0\n"
+    );
+}
+
+#[test]
+fn test_ragged_rows() {
+    let err = Table::parse("1\t2\t3\n4\t5\n", Delimiter::Char('\t'), false).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "day 1, line 2, column 4: expected 3 numbers `4\t5`"
+    );
+}
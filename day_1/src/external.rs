@@ -0,0 +1,347 @@
+//! Part 1 for lists too large to hold in memory.
+//!
+//! Each column is cut into sorted runs that are spilled to temporary files.
+//! While a column has more runs than can be opened at once, groups of them
+//! are merged into longer runs. Then the runs of both columns are k-way
+//! merged in step so the distances can be summed without ever holding a
+//! whole column.
+
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    error::Error,
+    fmt,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, BufWriter, Read, Write},
+    path::{Path, PathBuf},
+    process,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use aoc_common::{parse::Line, ParseError, Solution};
+
+use crate::{
+    columns::{parse_row, Delimiter},
+    Day01,
+};
+
+/// Settings for [`ExternalSort::total_distance`].
+#[derive(Debug, Clone)]
+pub struct ExternalSort {
+    /// How many numbers of each column are sorted in memory per run.
+    pub run_len: usize,
+    /// Where the run files are written; a fresh directory is made inside it.
+    pub temp_dir: PathBuf,
+    /// The most runs of a column read at once. The final merge reads both
+    /// columns, so up to twice this many files are open then.
+    pub fan_in: usize,
+}
+
+impl Default for ExternalSort {
+    fn default() -> Self {
+        Self {
+            run_len: 1 << 20,
+            temp_dir: std::env::temp_dir(),
+            fan_in: 64,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ExternalError {
+    Parse(ParseError),
+    Io(io::Error),
+}
+
+impl fmt::Display for ExternalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExternalError::Parse(err) => write!(f, "{err}"),
+            ExternalError::Io(err) => write!(f, "external sort failed: {err}"),
+        }
+    }
+}
+
+impl Error for ExternalError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ExternalError::Parse(err) => Some(err),
+            ExternalError::Io(err) => Some(err),
+        }
+    }
+}
+
+impl From<ParseError> for ExternalError {
+    fn from(err: ParseError) -> Self {
+        ExternalError::Parse(err)
+    }
+}
+
+impl From<io::Error> for ExternalError {
+    fn from(err: io::Error) -> Self {
+        ExternalError::Io(err)
+    }
+}
+
+impl ExternalSort {
+    /// The total distance between the two lists read from `reader`, the same
+    /// answer as part 1 but computed with bounded memory.
+    pub fn total_distance(&self, reader: impl BufRead) -> Result<u128, ExternalError> {
+        let dir = TempDir::new(&self.temp_dir)?;
+        let run_len = self.run_len.max(1);
+        let mut left = RunWriter::new(&dir.path, "left", run_len);
+        let mut right = RunWriter::new(&dir.path, "right", run_len);
+
+        for (idx, text) in reader.lines().enumerate() {
+            let text = text?;
+            let line = Line {
+                day: Day01::DAY,
+                number: idx + 1,
+                text: &text,
+            };
+            let row = parse_row(&line, Delimiter::Whitespace, 2)?;
+            left.push(row[0])?;
+            right.push(row[1])?;
+        }
+
+        let left = Merge::new(&self.reduce(&dir.path, "left", left.finish()?)?)?;
+        let right = Merge::new(&self.reduce(&dir.path, "right", right.finish()?)?)?;
+
+        let mut total = 0u128;
+        for (left_num, right_num) in left.zip(right) {
+            total += u128::from(left_num?.abs_diff(right_num?));
+        }
+        Ok(total)
+    }
+
+    /// Merges `runs` in groups of at most `fan_in`, pass after pass, until
+    /// no more than `fan_in` are left. Merged runs are removed as they go.
+    fn reduce(&self, dir: &Path, column: &str, mut runs: Vec<PathBuf>) -> io::Result<Vec<PathBuf>> {
+        let fan_in = self.fan_in.max(2);
+        let mut pass = 0;
+        while runs.len() > fan_in {
+            pass += 1;
+            let mut merged = Vec::with_capacity(runs.len().div_ceil(fan_in));
+            for group in runs.chunks(fan_in) {
+                let path = dir.join(format!("{column}-pass{pass}-{}.run", merged.len()));
+                write_run(&path, Merge::new(group)?)?;
+                for run in group {
+                    fs::remove_file(run)?;
+                }
+                merged.push(path);
+            }
+            runs = merged;
+        }
+        Ok(runs)
+    }
+}
+
+/// A uniquely named directory that is removed with everything in it on drop.
+struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    fn new(parent: &Path) -> io::Result<Self> {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.subsec_nanos());
+        let path = parent.join(format!(
+            "day01-sort-{}-{nanos}-{}",
+            process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::create_dir(&path)?;
+        Ok(Self { path })
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+/// Buffers one column and spills it as sorted runs of native-endian `u32`s.
+struct RunWriter<'a> {
+    dir: &'a Path,
+    column: &'static str,
+    run_len: usize,
+    buffer: Vec<u32>,
+    runs: Vec<PathBuf>,
+}
+
+impl<'a> RunWriter<'a> {
+    fn new(dir: &'a Path, column: &'static str, run_len: usize) -> Self {
+        Self {
+            dir,
+            column,
+            run_len,
+            buffer: Vec::with_capacity(run_len),
+            runs: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, num: u32) -> io::Result<()> {
+        self.buffer.push(num);
+        if self.buffer.len() >= self.run_len {
+            self.spill()?;
+        }
+        Ok(())
+    }
+
+    fn spill(&mut self) -> io::Result<()> {
+        self.buffer.sort_unstable();
+        let path = self
+            .dir
+            .join(format!("{}-{}.run", self.column, self.runs.len()));
+        write_run(&path, self.buffer.drain(..).map(Ok))?;
+        self.runs.push(path);
+        Ok(())
+    }
+
+    fn finish(mut self) -> io::Result<Vec<PathBuf>> {
+        if !self.buffer.is_empty() {
+            self.spill()?;
+        }
+        Ok(self.runs)
+    }
+}
+
+/// Writes a run as native-endian `u32`s.
+fn write_run(path: &Path, nums: impl Iterator<Item = io::Result<u32>>) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    for num in nums {
+        writer.write_all(&num?.to_ne_bytes())?;
+    }
+    writer.flush()
+}
+
+struct RunReader(BufReader<File>);
+
+impl RunReader {
+    fn next(&mut self) -> io::Result<Option<u32>> {
+        let mut bytes = [0; 4];
+        match self.0.read_exact(&mut bytes) {
+            Ok(()) => Ok(Some(u32::from_ne_bytes(bytes))),
+            Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+/// Yields the numbers of several sorted runs in ascending order.
+struct Merge {
+    runs: Vec<RunReader>,
+    heads: BinaryHeap<Reverse<(u32, usize)>>,
+}
+
+impl Merge {
+    fn new(paths: &[PathBuf]) -> io::Result<Self> {
+        let mut runs = Vec::new();
+        let mut heads = BinaryHeap::new();
+        for (idx, path) in paths.iter().enumerate() {
+            let mut run = RunReader(BufReader::new(File::open(path)?));
+            if let Some(num) = run.next()? {
+                heads.push(Reverse((num, idx)));
+            }
+            runs.push(run);
+        }
+        Ok(Self { runs, heads })
+    }
+}
+
+impl Iterator for Merge {
+    type Item = io::Result<u32>;
+
+    fn next(&mut self) -> Option<io::Result<u32>> {
+        let Reverse((num, idx)) = self.heads.pop()?;
+        match self.runs[idx].next() {
+            Ok(Some(next)) => self.heads.push(Reverse((next, idx))),
+            Ok(None) => (),
+            Err(err) => return Some(Err(err)),
+        }
+        Some(Ok(num))
+    }
+}
+
+#[test]
+fn test_matches_in_memory() {
+    let mut rng = aoc_common::random::Lcg::new(0x2024);
+    let input: String = (0..1000)
+        .map(|_| format!("{}   {}\n", rng.below(100_000), rng.below(100_000)))
+        .collect();
+
+    let lists = Day01::parse(&input).unwrap();
+    let expected = Day01::part1(&lists).unwrap().to_string();
+
+    let sort = ExternalSort {
+        run_len: 7,
+        ..ExternalSort::default()
+    };
+    let total = sort.total_distance(input.as_bytes()).unwrap();
+    assert_eq!(total.to_string(), expected);
+}
+
+#[test]
+fn test_more_runs_than_fan_in() {
+    let input: String = (0..200)
+        .map(|idx| format!("{}   {}\n", (idx * 37) % 101, (idx * 53) % 89))
+        .collect();
+    let lists = Day01::parse(&input).unwrap();
+    let expected = Day01::part1(&lists).unwrap().to_string();
+
+    let sort = ExternalSort {
+        run_len: 1,
+        fan_in: 3,
+        ..ExternalSort::default()
+    };
+    let total = sort.total_distance(input.as_bytes()).unwrap();
+    assert_eq!(total.to_string(), expected);
+
+    // 200 runs take four passes to get down to 3, leaving nothing behind.
+    let dir = TempDir::new(&std::env::temp_dir()).unwrap();
+    let mut writer = RunWriter::new(&dir.path, "left", 1);
+    for num in lists.left.iter().copied() {
+        writer.push(num).unwrap();
+    }
+    let runs = sort
+        .reduce(&dir.path, "left", writer.finish().unwrap())
+        .unwrap();
+    assert_eq!(runs.len(), 3);
+    assert_eq!(fs::read_dir(&dir.path).unwrap().count(), 3);
+    assert!(runs
+        .iter()
+        .all(|run| run.to_string_lossy().contains("pass4")));
+    let merged: Vec<u32> = Merge::new(&runs).unwrap().map(Result::unwrap).collect();
+    let mut sorted = lists.left.clone();
+    sorted.sort_unstable();
+    assert_eq!(merged, sorted);
+}
+
+#[test]
+fn test_example_and_cleanup() {
+    let parent = TempDir::new(&std::env::temp_dir()).unwrap();
+    let sort = ExternalSort {
+        run_len: 2,
+        temp_dir: parent.path.clone(),
+        ..ExternalSort::default()
+    };
+    let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+    assert_eq!(sort.total_distance(input.as_bytes()).unwrap(), 11);
+    assert_eq!(fs::read_dir(&parent.path).unwrap().count(), 0);
+}
+
+#[test]
+fn test_parse_error() {
+    let err = ExternalSort::default()
+        .total_distance("3   4\n4   3x\n".as_bytes())
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "day 1, line 2, column 5: invalid number `3x`"
+    );
+}
@@ -0,0 +1,81 @@
+use aoc_common::{Answer, ParseError, Solution, SolveError};
+
+use crate::columns::{similarity, sorted_distance, Delimiter, Table};
+
+pub mod columns;
+pub mod external;
+pub mod report;
+
+pub struct Day01;
+
+pub struct Lists {
+    left: Vec<u32>,
+    right: Vec<u32>,
+}
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Parsed = Lists;
+
+    /// The puzzle's two lists are a [`Table`] of exactly two columns.
+    fn parse(input: &str) -> Result<Lists, ParseError> {
+        let table = Table::parse(input, Delimiter::Whitespace, false)?;
+        match <[Vec<u32>; 2]>::try_from(table.columns) {
+            Ok([left, right]) => Ok(Lists { left, right }),
+            Err(columns) => Err(ParseError::whole_input(
+                Self::DAY,
+                format!("expected two columns, found {}", columns.len()),
+            )),
+        }
+    }
+
+    fn part1(lists: &Lists) -> Result<Answer, SolveError> {
+        Ok(sorted_distance(&lists.left, &lists.right).into())
+    }
+
+    fn part2(lists: &Lists) -> Result<Answer, SolveError> {
+        Ok(similarity(&lists.left, &lists.right).into())
+    }
+}
+
+#[test]
+fn test_parse_invalid_number() {
+    let err = Day01::parse("3   4\n4   3x\n").err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "day 1, line 2, column 5: invalid number `3x`"
+    );
+}
+
+#[test]
+fn test_parse_missing_column() {
+    let err = Day01::parse("3   4\n4\n").err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "day 1, line 2, column 2: expected 2 numbers `4`"
+    );
+}
+
+#[test]
+fn test_parse_column_count() {
+    for (input, found) in [
+        ("", 0),
+        (
+            "3
+4
+", 1,
+        ),
+        (
+            "3   4   5
+",
+            3,
+        ),
+    ] {
+        let err = Day01::parse(input).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            format!("day 1: expected two columns, found {found}")
+        );
+    }
+}
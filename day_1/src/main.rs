@@ -0,0 +1,170 @@
+use std::{path::PathBuf, process::ExitCode};
+
+use aoc_common::{
+    input::Source,
+    runner::{self, Args, Modes},
+    Solution,
+};
+use day01::{
+    columns::{Delimiter, Table},
+    external::ExternalSort,
+    report::Report,
+    Day01,
+};
+
+const USAGE: &str = "\
+Usage:
+    day01 [--input <PATH> | --example]
+    day01 --external [--run-len <N>] [--fan-in <F>] [--temp-dir <DIR>]
+                   [--input <PATH> | --example]
+    day01 --report [--top <K>] [--json] [--input <PATH> | --example]
+    day01 --matrix [--delimiter <D>] [--header] [--input <PATH> | --example]
+
+--external solves part 1 with bounded memory by spilling sorted runs of
+<N> numbers per column (default 1048576) to <DIR> (default: the system
+temporary directory) and merging them, at most <F> (default 64) runs of a
+column at a time.
+
+--report prints statistics about both lists, listing the <K> (default 10)
+highest entries of each ranking, as text or with --json as JSON.
+
+--matrix reads any number of columns and prints the part 1 distance and
+part 2 similarity score for every pair of them. <D> is `whitespace` (the
+default), `csv`, `tsv` or a single character; with --header the first row
+names the columns.";
+
+enum Mode {
+    External(ExternalSort),
+    Report { top: usize, json: bool },
+    Matrix { delimiter: Delimiter, header: bool },
+}
+
+impl Modes for Mode {
+    fn usage(_: u8) -> String {
+        USAGE.to_string()
+    }
+
+    fn parse(args: &mut Args<'_>) -> Result<Option<Self>, String> {
+        let (mut external, mut report, mut matrix) = (false, false, false);
+        let mut sort = ExternalSort::default();
+        let mut sort_options = None;
+        let mut top = None;
+        let mut json = false;
+        let mut delimiter = None;
+        let mut header = false;
+
+        while let Some(arg) = args.next_arg()? {
+            let mut value = || args.value(arg);
+            match arg {
+                "--external" => external = true,
+                "--report" => report = true,
+                "--matrix" => matrix = true,
+                "--run-len" => {
+                    let value = value()?;
+                    sort.run_len = value
+                        .parse()
+                        .ok()
+                        .filter(|&len| len > 0)
+                        .ok_or_else(|| format!("Invalid run length `{value}`"))?;
+                    sort_options = Some(arg);
+                }
+                "--fan-in" => {
+                    let value = value()?;
+                    sort.fan_in = value
+                        .parse()
+                        .ok()
+                        .filter(|&fan_in| fan_in > 1)
+                        .ok_or_else(|| format!("Invalid fan-in `{value}`"))?;
+                    sort_options = Some(arg);
+                }
+                "--temp-dir" => {
+                    sort.temp_dir = PathBuf::from(value()?);
+                    sort_options = Some(arg);
+                }
+                "--top" => {
+                    let value = value()?;
+                    top = Some(
+                        value
+                            .parse()
+                            .map_err(|_| format!("Invalid count `{value}`"))?,
+                    );
+                }
+                "--json" => json = true,
+                "--delimiter" => {
+                    let value = value()?;
+                    delimiter = Some(
+                        Delimiter::from_name(value)
+                            .ok_or_else(|| format!("Invalid delimiter `{value}`"))?,
+                    );
+                }
+                "--header" => header = true,
+                other => return Err(format!("Unknown argument `{other}`")),
+            }
+        }
+
+        runner::exclusive(&[
+            ("--external", external),
+            ("--report", report),
+            ("--matrix", matrix),
+        ])?;
+        if let Some(option) = sort_options {
+            runner::requires((option, true), ("--external", external))?;
+        }
+        runner::requires(("--top", top.is_some()), ("--report", report))?;
+        runner::requires(("--json", json), ("--report", report))?;
+        runner::requires(("--delimiter", delimiter.is_some()), ("--matrix", matrix))?;
+        runner::requires(("--header", header), ("--matrix", matrix))?;
+
+        let mode = if external {
+            Some(Mode::External(sort))
+        } else if report {
+            Some(Mode::Report {
+                top: top.unwrap_or(10),
+                json,
+            })
+        } else if matrix {
+            Some(Mode::Matrix {
+                delimiter: delimiter.unwrap_or(Delimiter::Whitespace),
+                header,
+            })
+        } else {
+            None
+        };
+        Ok(mode)
+    }
+
+    fn run(self, source: &Source) -> Result<(), String> {
+        match self {
+            Mode::External(sort) => {
+                let reader = source.open().map_err(|err| err.to_string())?;
+                let total = sort.total_distance(reader).map_err(|err| err.to_string())?;
+                println!("Part 1: {total}");
+            }
+            Mode::Report { top, json } => {
+                let input = source.read().map_err(|err| err.to_string())?;
+                let lists = Day01::parse(&input).map_err(|err| err.to_string())?;
+                let report = Report::new(&lists, top);
+                if json {
+                    println!("{}", report.to_json());
+                } else {
+                    print!("{report}");
+                }
+            }
+            Mode::Matrix { delimiter, header } => {
+                let input = source.read().map_err(|err| err.to_string())?;
+                let table =
+                    Table::parse(&input, delimiter, header).map_err(|err| err.to_string())?;
+                println!("Sorted distance:");
+                print!("{}", table.distance_matrix());
+                println!();
+                println!("Similarity (row IDs x count in column):");
+                print!("{}", table.similarity_matrix());
+            }
+        }
+        Ok(())
+    }
+}
+
+fn main() -> ExitCode {
+    runner::main_with::<Day01, Mode>()
+}
@@ -0,0 +1,246 @@
+//! Statistics about the two location-ID lists, for `day01 --report`.
+
+use std::{
+    cmp::Reverse,
+    collections::{BTreeSet, HashMap},
+    fmt,
+    iter::zip,
+};
+
+use aoc_common::json::Json;
+
+use crate::Lists;
+
+pub struct ColumnStats {
+    pub len: usize,
+    pub distinct: usize,
+    /// Entries that repeat an ID seen earlier in the column.
+    pub duplicates: usize,
+    pub min: Option<u32>,
+    pub max: Option<u32>,
+    /// The lower of the two middle IDs when the column has an even length.
+    pub median: Option<u32>,
+}
+
+/// How much an ID contributes to the similarity score.
+pub struct Similarity {
+    pub id: u32,
+    pub count: u64,
+    pub score: u64,
+}
+
+/// A pair of IDs matched up by rank, as in part 1.
+pub struct Pair {
+    pub left: u32,
+    pub right: u32,
+    pub distance: u32,
+}
+
+pub struct Report {
+    pub left: ColumnStats,
+    pub right: ColumnStats,
+    pub most_similar: Vec<Similarity>,
+    pub largest_distances: Vec<Pair>,
+    pub only_left: Vec<u32>,
+    pub only_right: Vec<u32>,
+}
+
+impl ColumnStats {
+    fn new(sorted: &[u32]) -> Self {
+        let distinct = sorted.iter().collect::<BTreeSet<_>>().len();
+        Self {
+            len: sorted.len(),
+            distinct,
+            duplicates: sorted.len() - distinct,
+            min: sorted.first().copied(),
+            max: sorted.last().copied(),
+            median: sorted.len().checked_sub(1).map(|last| sorted[last / 2]),
+        }
+    }
+
+    fn to_json(&self) -> Json {
+        Json::object([
+            ("len", self.len.into()),
+            ("distinct", self.distinct.into()),
+            ("duplicates", self.duplicates.into()),
+            ("min", self.min.into()),
+            ("max", self.max.into()),
+            ("median", self.median.into()),
+        ])
+    }
+}
+
+impl Report {
+    /// Builds the report, keeping the `top` highest entries of each ranking.
+    pub fn new(lists: &Lists, top: usize) -> Self {
+        let mut left = lists.left.clone();
+        let mut right = lists.right.clone();
+        left.sort_unstable();
+        right.sort_unstable();
+
+        let mut right_counts: HashMap<u32, u64> = HashMap::new();
+        right
+            .iter()
+            .for_each(|&id| *right_counts.entry(id).or_default() += 1);
+
+        let left_ids: BTreeSet<u32> = left.iter().copied().collect();
+        let right_ids: BTreeSet<u32> = right.iter().copied().collect();
+
+        let mut most_similar: Vec<Similarity> = left_ids
+            .iter()
+            .filter_map(|&id| {
+                let count = *right_counts.get(&id)?;
+                Some(Similarity {
+                    id,
+                    count,
+                    score: u64::from(id) * count,
+                })
+            })
+            .collect();
+        most_similar.sort_by(|a, b| b.score.cmp(&a.score).then(a.id.cmp(&b.id)));
+        most_similar.truncate(top);
+
+        let mut largest_distances: Vec<Pair> = zip(&left, &right)
+            .map(|(&left, &right)| Pair {
+                left,
+                right,
+                distance: left.abs_diff(right),
+            })
+            .collect();
+        largest_distances.sort_by_key(|pair| Reverse(pair.distance));
+        largest_distances.truncate(top);
+
+        Self {
+            left: ColumnStats::new(&left),
+            right: ColumnStats::new(&right),
+            most_similar,
+            largest_distances,
+            only_left: left_ids.difference(&right_ids).copied().collect(),
+            only_right: right_ids.difference(&left_ids).copied().collect(),
+        }
+    }
+
+    pub fn to_json(&self) -> Json {
+        let most_similar = self
+            .most_similar
+            .iter()
+            .map(|similarity| {
+                Json::object([
+                    ("id", similarity.id.into()),
+                    ("count", similarity.count.into()),
+                    ("score", similarity.score.into()),
+                ])
+            })
+            .collect();
+        let largest_distances = self
+            .largest_distances
+            .iter()
+            .map(|pair| {
+                Json::object([
+                    ("left", pair.left.into()),
+                    ("right", pair.right.into()),
+                    ("distance", pair.distance.into()),
+                ])
+            })
+            .collect();
+
+        Json::object([
+            ("left", self.left.to_json()),
+            ("right", self.right.to_json()),
+            ("most_similar", Json::Array(most_similar)),
+            ("largest_distances", Json::Array(largest_distances)),
+            ("only_left", self.only_left.clone().into()),
+            ("only_right", self.only_right.clone().into()),
+        ])
+    }
+}
+
+fn write_optional(f: &mut fmt::Formatter<'_>, value: Option<u32>) -> fmt::Result {
+    match value {
+        Some(value) => write!(f, "{value}"),
+        None => write!(f, "-"),
+    }
+}
+
+impl fmt::Display for ColumnStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} IDs, {} distinct, {} duplicates, min ",
+            self.len, self.distinct, self.duplicates
+        )?;
+        write_optional(f, self.min)?;
+        write!(f, ", max ")?;
+        write_optional(f, self.max)?;
+        write!(f, ", median ")?;
+        write_optional(f, self.median)
+    }
+}
+
+fn write_ids(f: &mut fmt::Formatter<'_>, label: &str, ids: &[u32]) -> fmt::Result {
+    let ids: Vec<String> = ids.iter().map(u32::to_string).collect();
+    writeln!(f, "{label} ({}): {}", ids.len(), ids.join(", "))
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Left column: {}", self.left)?;
+        writeln!(f, "Right column: {}", self.right)?;
+
+        writeln!(f, "Most similar IDs (ID x count in right column):")?;
+        for similarity in &self.most_similar {
+            writeln!(
+                f,
+                "  {} x {} = {}",
+                similarity.id, similarity.count, similarity.score
+            )?;
+        }
+
+        writeln!(f, "Largest distances between paired IDs:")?;
+        for pair in &self.largest_distances {
+            writeln!(f, "  {} <-> {}: {}", pair.left, pair.right, pair.distance)?;
+        }
+
+        write_ids(f, "Only in left column", &self.only_left)?;
+        write_ids(f, "Only in right column", &self.only_right)
+    }
+}
+
+#[test]
+fn test_example_report() {
+    let lists = Lists {
+        left: vec![3, 4, 2, 1, 3, 3],
+        right: vec![4, 3, 5, 3, 9, 3],
+    };
+    let report = Report::new(&lists, 2);
+
+    assert_eq!(
+        report.to_string(),
+        "Left column: 6 IDs, 4 distinct, 2 duplicates, min 1, max 4, median 3\n\
+         Right column: 6 IDs, 4 distinct, 2 duplicates, min 3, max 9, median 3\n\
+         Most similar IDs (ID x count in right column):\n  \
+         3 x 3 = 9\n  \
+         4 x 1 = 4\n\
+         Largest distances between paired IDs:\n  \
+         4 <-> 9: 5\n  \
+         1 <-> 3: 2\n\
+         Only in left column (2): 1, 2\n\
+         Only in right column (2): 5, 9\n"
+    );
+}
+
+#[test]
+fn test_report_json() {
+    let lists = Lists {
+        left: vec![1],
+        right: vec![2],
+    };
+    let json = Report::new(&lists, 5).to_json().to_string();
+
+    assert!(json.starts_with(
+        r#"{"left":{"len":1,"distinct":1,"duplicates":0,"min":1,"max":1,"median":1}"#
+    ));
+    assert!(json.ends_with(
+        r#""most_similar":[],"largest_distances":[{"left":1,"right":2,"distance":1}],"only_left":[1],"only_right":[2]}"#
+    ));
+}
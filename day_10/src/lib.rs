@@ -0,0 +1,114 @@
+use std::collections::HashSet;
+
+use aoc_common::{Answer, Grid, ParseError, Point, Solution, SolveError};
+
+pub struct Map {
+    map: Grid<u32>,
+}
+
+impl Map {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let map = Grid::parse(Day10::DAY, input, |line, idx, c| line.digit(idx, c))?;
+
+        Ok(Self { map })
+    }
+
+    fn get_hiking_scores(&self) -> Grid<u32> {
+        self.count_trails(true)
+    }
+
+    fn get_hiking_ratings(&self) -> Grid<u32> {
+        self.count_trails(false)
+    }
+
+    // Counts the trails from every trailhead, either to distinct peaks (the
+    // score) or along distinct paths (the rating).
+    fn count_trails(&self, distinct_peaks: bool) -> Grid<u32> {
+        self.map.map(|trailhead, &height| {
+            if height != 0 {
+                return 0;
+            }
+
+            let mut score = 0;
+            let mut paths = vec![trailhead];
+            let mut visited_plateaus: HashSet<Point> = HashSet::new();
+
+            while let Some(current_loc) = paths.pop() {
+                if self.map[current_loc] == 9 {
+                    if !distinct_peaks || visited_plateaus.insert(current_loc) {
+                        score += 1;
+                    }
+                } else {
+                    paths.extend(self.get_next_step_in_trail(current_loc));
+                }
+            }
+            score
+        })
+    }
+
+    fn get_next_step_in_trail(&self, current_loc: Point) -> Vec<Point> {
+        let current_loc_height = self.map[current_loc];
+        self.map
+            .neighbours4(current_loc)
+            .filter(|&next_loc| self.map[next_loc] == current_loc_height + 1)
+            .collect()
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Parsed = Map;
+
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        Map::new(input)
+    }
+
+    fn part1(map: &Map) -> Result<Answer, SolveError> {
+        Ok(map
+            .get_hiking_scores()
+            .iter()
+            .map(|(_, score)| score)
+            .sum::<u32>()
+            .into())
+    }
+
+    fn part2(map: &Map) -> Result<Answer, SolveError> {
+        Ok(map
+            .get_hiking_ratings()
+            .iter()
+            .map(|(_, score)| score)
+            .sum::<u32>()
+            .into())
+    }
+}
+
+#[test]
+fn test_map_creation() {
+    let input = "0123\n4567\n8912";
+    let map = Map::new(input).unwrap();
+    let expected = Grid::from_rows(vec![vec![0, 1, 2, 3], vec![4, 5, 6, 7], vec![8, 9, 1, 2]]);
+
+    assert_eq!(map.map, expected);
+}
+
+#[test]
+fn test_next_locations() {
+    let input = "2222\n2112\n2222";
+    let map = Map::new(input).unwrap();
+    let next_locations = map.get_next_step_in_trail(Point::new(2, 1));
+    let expected = vec![Point::new(2, 0), Point::new(3, 1), Point::new(2, 2)];
+
+    assert_eq!(next_locations, expected);
+}
+
+#[test]
+fn test_map_invalid_height() {
+    let err = Map::new("0123\n45.7").err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "day 10, line 2, column 3: expected a digit `.`"
+    );
+}
@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day10::Day10;
+
+fn main() -> ExitCode {
+    aoc_common::runner::main::<Day10>()
+}
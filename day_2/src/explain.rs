@@ -0,0 +1,228 @@
+//! Per-report diagnostics for `day02 --explain`.
+
+use std::fmt;
+
+use aoc_common::json::Json;
+
+use crate::policy::{Directions, SafetyPolicy};
+
+/// The rule broken by a pair of adjacent levels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    ZeroStep,
+    StepTooSmall,
+    StepTooLarge,
+    /// The levels turned around after going the other way.
+    DirectionChange,
+    /// The levels went the way the policy does not allow.
+    WrongDirection,
+}
+
+impl Rule {
+    pub fn name(self) -> &'static str {
+        match self {
+            Rule::ZeroStep => "zero_step",
+            Rule::StepTooSmall => "step_too_small",
+            Rule::StepTooLarge => "step_too_large",
+            Rule::DirectionChange => "direction_change",
+            Rule::WrongDirection => "wrong_direction",
+        }
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name().replace('_', " "))
+    }
+}
+
+/// The first pair of adjacent levels that breaks the policy, at `index` and
+/// `index + 1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    pub index: usize,
+    pub from: u32,
+    pub to: u32,
+    pub rule: Rule,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "index {}: {} -> {}, {}",
+            self.index, self.from, self.to, self.rule
+        )
+    }
+}
+
+/// Finds the first violation when no levels are removed.
+pub fn first_violation(policy: &SafetyPolicy, report: &[u32]) -> Option<Violation> {
+    let fixed = match policy.directions {
+        Directions::Increasing => Some(true),
+        Directions::Decreasing => Some(false),
+        Directions::Either => None,
+    };
+    let mut trend = fixed;
+
+    report.windows(2).enumerate().find_map(|(index, pair)| {
+        let (from, to) = (pair[0], pair[1]);
+        let violation = |rule| {
+            Some(Violation {
+                index,
+                from,
+                to,
+                rule,
+            })
+        };
+        if from == to {
+            return if policy.allow_plateaus {
+                None
+            } else {
+                violation(Rule::ZeroStep)
+            };
+        }
+
+        let increasing = to > from;
+        let step = from.abs_diff(to);
+        if trend.is_some_and(|trend| trend != increasing) {
+            violation(if fixed.is_some() {
+                Rule::WrongDirection
+            } else {
+                Rule::DirectionChange
+            })
+        } else if step < policy.min_step {
+            violation(Rule::StepTooSmall)
+        } else if step > policy.max_step {
+            violation(Rule::StepTooLarge)
+        } else {
+            trend = Some(increasing);
+            None
+        }
+    })
+}
+
+pub struct Explanation {
+    /// 1-based line of the report in the input.
+    pub line: usize,
+    /// Why the report is unsafe as it stands, if it is.
+    pub violation: Option<Violation>,
+    /// The levels whose removal makes the report safe, empty if it already
+    /// is, or `None` if the dampener cannot rescue it.
+    pub removed: Option<Vec<usize>>,
+}
+
+impl Explanation {
+    pub fn new(line: usize, policy: &SafetyPolicy, report: &[u32]) -> Self {
+        Self {
+            line,
+            violation: first_violation(policy, report),
+            removed: policy.dampen(report),
+        }
+    }
+
+    pub fn status(&self) -> &'static str {
+        match (&self.violation, &self.removed) {
+            (None, _) => "safe",
+            (Some(_), Some(_)) => "rescued",
+            (Some(_), None) => "unsafe",
+        }
+    }
+
+    pub fn to_json(&self) -> Json {
+        let violation = self.violation.map(|violation| {
+            Json::object([
+                ("index", violation.index.into()),
+                ("from", violation.from.into()),
+                ("to", violation.to.into()),
+                ("rule", violation.rule.name().into()),
+            ])
+        });
+        Json::object([
+            ("line", self.line.into()),
+            ("status", self.status().into()),
+            ("violation", violation.unwrap_or(Json::Null)),
+            ("removed", self.removed.clone().into()),
+        ])
+    }
+}
+
+pub fn explain(reports: &[Vec<u32>], policy: &SafetyPolicy) -> Vec<Explanation> {
+    reports
+        .iter()
+        .enumerate()
+        .map(|(idx, report)| Explanation::new(idx + 1, policy, report))
+        .collect()
+}
+
+pub fn table_header() -> String {
+    format!(
+        "{:>5}  {:<7}  {:<34}  {}",
+        "line", "status", "first violation", "removed"
+    )
+}
+
+/// One row of the table under [`table_header`].
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let violation = self
+            .violation
+            .map_or_else(|| "-".to_string(), |violation| violation.to_string());
+        let removed = match &self.removed {
+            Some(removed) if !removed.is_empty() => removed
+                .iter()
+                .map(usize::to_string)
+                .collect::<Vec<_>>()
+                .join(", "),
+            _ => "-".to_string(),
+        };
+        write!(
+            f,
+            "{:>5}  {:<7}  {violation:<34}  {removed}",
+            self.line,
+            self.status()
+        )
+    }
+}
+
+#[test]
+fn test_explain_example() {
+    let reports = [
+        vec![7, 6, 4, 2, 1],
+        vec![1, 2, 7, 8, 9],
+        vec![9, 7, 6, 2, 1],
+        vec![1, 3, 2, 4, 5],
+        vec![8, 6, 4, 4, 1],
+        vec![1, 3, 6, 7, 9],
+    ];
+    let rows: Vec<String> = explain(&reports, &SafetyPolicy::PART2)
+        .iter()
+        .map(Explanation::to_string)
+        .collect();
+
+    assert_eq!(
+        rows,
+        [
+            "    1  safe     -                                   -",
+            "    2  unsafe   index 1: 2 -> 7, step too large     -",
+            "    3  unsafe   index 2: 6 -> 2, step too large     -",
+            "    4  rescued  index 1: 3 -> 2, direction change   1",
+            "    5  rescued  index 2: 4 -> 4, zero step          2",
+            "    6  safe     -                                   -",
+        ]
+    );
+}
+
+#[test]
+fn test_explain_json() {
+    let policy = SafetyPolicy {
+        directions: Directions::Decreasing,
+        ..SafetyPolicy::PART2
+    };
+    let explanation = Explanation::new(3, &policy, &[1, 2, 3]);
+
+    assert_eq!(
+        explanation.to_json().to_string(),
+        r#"{"line":3,"status":"unsafe","violation":{"index":0,"from":1,"to":2,"rule":"wrong_direction"},"removed":null}"#
+    );
+}
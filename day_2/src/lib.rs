@@ -0,0 +1,96 @@
+use aoc_common::{
+    parse::{lines, Line},
+    Answer, ParseError, Solution, SolveError,
+};
+
+use crate::policy::SafetyPolicy;
+
+pub mod explain;
+pub mod parallel;
+pub mod policy;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Parsed = Vec<Vec<u32>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+        lines(Self::DAY, input)
+            .map(|line| parse_report(&line))
+            .collect()
+    }
+
+    fn part1(reports: &Vec<Vec<u32>>) -> Result<Answer, SolveError> {
+        Ok(count_safe(reports, &SafetyPolicy::PART1).into())
+    }
+
+    fn part2(reports: &Vec<Vec<u32>>) -> Result<Answer, SolveError> {
+        Ok(count_safe(reports, &SafetyPolicy::PART2).into())
+    }
+}
+
+fn parse_report(line: &Line) -> Result<Vec<u32>, ParseError> {
+    line.text
+        .split_whitespace()
+        .map(|num| line.number::<u32>(num))
+        .collect()
+}
+
+pub fn count_safe(reports: &[Vec<u32>], policy: &SafetyPolicy) -> usize {
+    reports
+        .iter()
+        .filter(|report| policy.is_safe(report))
+        .count()
+}
+
+#[cfg(test)]
+fn valid_report(report: &[u32]) -> bool {
+    SafetyPolicy::PART2.is_safe(report)
+}
+
+#[test]
+fn simple_valid_report() {
+    let result = valid_report(&[7, 6, 4, 2, 1]);
+    assert!(result);
+}
+
+#[test]
+fn invalid_report() {
+    let result = valid_report(&[1, 2, 7, 8, 9]);
+    assert!(!result);
+}
+
+#[test]
+fn invalid_report_2() {
+    let result = valid_report(&[9, 7, 6, 2, 1]);
+    assert!(!result);
+}
+
+#[test]
+fn valid_report_after_removal() {
+    let result = valid_report(&[1, 3, 2, 4, 5]);
+    assert!(result);
+}
+
+#[test]
+fn valid_report_after_removal_at_end() {
+    let result = valid_report(&[9, 6, 5, 7]);
+    assert!(result);
+}
+
+#[test]
+fn valid_report_after_removal_at_start() {
+    let result = valid_report(&[4, 4, 3, 2, 1]);
+    assert!(result);
+}
+
+#[test]
+fn test_parse_invalid_level() {
+    let err = Day02::parse("7 6 4 2 1\n1 2 -7 8 9").err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "day 2, line 2, column 5: invalid number `-7`"
+    );
+}
@@ -0,0 +1,198 @@
+use std::{path::PathBuf, process::ExitCode};
+
+use aoc_common::{
+    input::Source,
+    runner::{self, Args, Modes},
+    Solution,
+};
+use day02::{
+    count_safe,
+    explain::{explain, table_header},
+    parallel::ParallelCount,
+    policy::SafetyPolicy,
+    Day02,
+};
+
+const USAGE: &str = "\
+Usage:
+    day02 [--input <PATH> | --example]
+    day02 [--policy <NAME>] [--config <PATH>] [--min-step <N>] [--max-step <N>]
+          [--directions <D>] [--allow-plateaus] [--budget <K>]
+          [--input <PATH> | --example]
+    day02 --explain [--json] [policy options] [--input <PATH> | --example]
+    day02 --parallel [--threads <N>] [--chunk-lines <N>] [policy options]
+          [--input <PATH> | --example]
+
+Any policy option counts the safe reports under a custom safety policy
+instead of solving both parts. The policy starts from the preset <NAME>
+(`part1` or `part2`, the default), then the settings in the config file,
+then the individual options. <D> is `increasing`, `decreasing` or
+`either`, and <K> is how many levels the Problem Dampener may remove.
+
+--explain lists every report as safe, rescued by the dampener or unsafe,
+with the first pair of levels that breaks the policy and the levels whose
+removal rescued it, as a table or with --json as JSON lines.
+
+--parallel streams the input through <N> worker threads (default: one per
+CPU) in chunks of <N> lines (default 4096), holding only a few chunks in
+memory. It solves both parts, or counts the safe reports under the policy
+if any policy option is given.";
+
+/// A policy as given on the command line. It is only built when the mode
+/// runs, so a bad config file or setting is an error rather than a usage
+/// mistake.
+struct PolicyArgs {
+    preset: SafetyPolicy,
+    config: Option<PathBuf>,
+    settings: Vec<(&'static str, String)>,
+}
+
+impl PolicyArgs {
+    /// Options may come in any order, but are layered preset, config, flags.
+    fn build(self) -> Result<SafetyPolicy, String> {
+        let mut policy = self.preset;
+        if let Some(config) = self.config {
+            policy.load_config(&config)?;
+        }
+        for (key, value) in self.settings {
+            policy.set(key, &value)?;
+        }
+        policy.validate()?;
+        Ok(policy)
+    }
+}
+
+enum Mode {
+    Count(PolicyArgs),
+    Explain { policy: PolicyArgs, json: bool },
+    Parallel(ParallelCount, Option<PolicyArgs>),
+}
+
+impl Modes for Mode {
+    fn usage(_: u8) -> String {
+        USAGE.to_string()
+    }
+
+    fn parse(args: &mut Args<'_>) -> Result<Option<Self>, String> {
+        let mut explain = false;
+        let mut json = false;
+        let mut parallel = false;
+        let mut count = ParallelCount::default();
+        let mut threads = None;
+        let mut chunk_lines = None;
+        let mut preset: Option<SafetyPolicy> = None;
+        let mut config: Option<PathBuf> = None;
+        let mut settings: Vec<(&str, String)> = Vec::new();
+
+        while let Some(arg) = args.next_arg()? {
+            let mut value = || args.value(arg);
+            match arg {
+                "--policy" => {
+                    let value = value()?;
+                    preset = Some(
+                        SafetyPolicy::preset(value)
+                            .ok_or_else(|| format!("Unknown policy `{value}`"))?,
+                    );
+                }
+                "--config" => config = Some(PathBuf::from(value()?)),
+                "--min-step" => settings.push(("min_step", value()?.to_string())),
+                "--max-step" => settings.push(("max_step", value()?.to_string())),
+                "--directions" => settings.push(("directions", value()?.to_string())),
+                "--allow-plateaus" => settings.push(("allow_plateaus", "true".to_string())),
+                "--budget" => settings.push(("budget", value()?.to_string())),
+                "--explain" => explain = true,
+                "--json" => json = true,
+                "--parallel" => parallel = true,
+                "--threads" => threads = Some(positive(value()?, "thread count")?),
+                "--chunk-lines" => chunk_lines = Some(positive(value()?, "chunk size")?),
+                other => return Err(format!("Unknown argument `{other}`")),
+            }
+        }
+
+        runner::exclusive(&[("--explain", explain), ("--parallel", parallel)])?;
+        runner::requires(("--json", json), ("--explain", explain))?;
+        runner::requires(("--threads", threads.is_some()), ("--parallel", parallel))?;
+        runner::requires(
+            ("--chunk-lines", chunk_lines.is_some()),
+            ("--parallel", parallel),
+        )?;
+        count.threads = threads.unwrap_or(count.threads);
+        count.chunk_lines = chunk_lines.unwrap_or(count.chunk_lines);
+
+        let custom = preset.is_some() || config.is_some() || !settings.is_empty();
+        if !custom && !explain && !parallel {
+            return Ok(None);
+        }
+
+        let policy = PolicyArgs {
+            preset: preset.unwrap_or(SafetyPolicy::PART2),
+            config,
+            settings,
+        };
+        let mode = if explain {
+            Mode::Explain { policy, json }
+        } else if parallel {
+            Mode::Parallel(count, custom.then_some(policy))
+        } else {
+            Mode::Count(policy)
+        };
+        Ok(Some(mode))
+    }
+
+    fn run(self, source: &Source) -> Result<(), String> {
+        match self {
+            Mode::Parallel(count, policy) => {
+                let policy = policy.map(PolicyArgs::build).transpose()?;
+                let reader = source.open().map_err(|err| err.to_string())?;
+                let policies = match &policy {
+                    Some(policy) => vec![policy.clone()],
+                    None => vec![SafetyPolicy::PART1, SafetyPolicy::PART2],
+                };
+                let counts = count
+                    .count_safe(reader, &policies)
+                    .map_err(|err| err.to_string())?;
+                match (policy, &counts[..]) {
+                    (None, [part1, part2]) => println!("Part 1: {part1}\nPart 2: {part2}"),
+                    _ => println!("Safe reports: {}", counts[0]),
+                }
+            }
+            Mode::Count(policy) => {
+                let policy = policy.build()?;
+                let reports = read_reports(source)?;
+                println!("Safe reports: {}", count_safe(&reports, &policy));
+            }
+            Mode::Explain { policy, json } => {
+                let policy = policy.build()?;
+                let explanations = explain(&read_reports(source)?, &policy);
+                if json {
+                    for explanation in &explanations {
+                        println!("{}", explanation.to_json());
+                    }
+                } else {
+                    println!("{}", table_header());
+                    for explanation in &explanations {
+                        println!("{explanation}");
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn positive(value: &str, what: &str) -> Result<usize, String> {
+    value
+        .parse()
+        .ok()
+        .filter(|&n| n > 0)
+        .ok_or_else(|| format!("Invalid {what} `{value}`"))
+}
+
+fn read_reports(source: &Source) -> Result<Vec<Vec<u32>>, String> {
+    let input = source.read().map_err(|err| err.to_string())?;
+    Day02::parse(&input).map_err(|err| err.to_string())
+}
+
+fn main() -> ExitCode {
+    runner::main_with::<Day02, Mode>()
+}
@@ -0,0 +1,303 @@
+//! Counting safe reports in files too large to hold in memory.
+//!
+//! One thread reads the input in chunks of lines and hands them to worker
+//! threads over a bounded channel, so only a few chunks per worker are ever
+//! held at once.
+
+use std::{
+    error::Error,
+    fmt,
+    io::{self, BufRead},
+    num::NonZeroUsize,
+    panic,
+    sync::{
+        mpsc::{self, Receiver, SyncSender},
+        Arc, Mutex,
+    },
+    thread,
+};
+
+use aoc_common::{parse::Line, ParseError, Solution};
+
+use crate::{parse_report, policy::SafetyPolicy, Day02};
+
+/// Settings for [`ParallelCount::count_safe`].
+#[derive(Debug, Clone)]
+pub struct ParallelCount {
+    pub threads: usize,
+    /// How many lines are handed to a worker at a time.
+    pub chunk_lines: usize,
+}
+
+impl Default for ParallelCount {
+    fn default() -> Self {
+        Self {
+            threads: thread::available_parallelism().map_or(1, NonZeroUsize::get),
+            chunk_lines: 4096,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum StreamError {
+    Parse(ParseError),
+    Io { line: usize, error: io::Error },
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Parse(err) => write!(f, "{err}"),
+            StreamError::Io { line, error } => write!(f, "unable to read line {line}: {error}"),
+        }
+    }
+}
+
+impl Error for StreamError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            StreamError::Parse(err) => Some(err),
+            StreamError::Io { error, .. } => Some(error),
+        }
+    }
+}
+
+struct Chunk {
+    first_line: usize,
+    lines: Vec<String>,
+}
+
+/// The error on the earliest line, whichever thread finds it first.
+#[derive(Default)]
+struct FirstError(Mutex<Option<(usize, StreamError)>>);
+
+impl FirstError {
+    fn line(&self) -> Option<usize> {
+        self.0.lock().unwrap().as_ref().map(|(line, _)| *line)
+    }
+
+    fn record(&self, line: usize, error: StreamError) {
+        let mut first = self.0.lock().unwrap();
+        if first.as_ref().is_none_or(|(first, _)| line < *first) {
+            *first = Some((line, error));
+        }
+    }
+}
+
+impl ParallelCount {
+    /// Counts the reports read from `reader` that are safe under each of
+    /// `policies`, giving the same counts as [`crate::count_safe`] on the
+    /// whole input. An error is reported for the earliest line that has one.
+    pub fn count_safe(
+        &self,
+        reader: impl BufRead,
+        policies: &[SafetyPolicy],
+    ) -> Result<Vec<usize>, StreamError> {
+        self.count_matching(reader, policies.len(), |index, report| {
+            policies[index].is_safe(report)
+        })
+    }
+
+    /// Keeps `counters` counts, where `is_safe(index, report)` decides
+    /// whether a report adds to the count at `index`.
+    fn count_matching(
+        &self,
+        reader: impl BufRead,
+        counters: usize,
+        is_safe: impl Fn(usize, &[u32]) -> bool + Sync,
+    ) -> Result<Vec<usize>, StreamError> {
+        let threads = self.threads.max(1);
+        let chunk_lines = self.chunk_lines.max(1);
+        let (sender, receiver) = mpsc::sync_channel(threads * 2);
+        let receiver = Arc::new(Mutex::new(receiver));
+        let first_error = FirstError::default();
+
+        let counts = thread::scope(|scope| {
+            let (is_safe, first_error) = (&is_safe, &first_error);
+            let workers: Vec<_> = (0..threads)
+                .map(|_| {
+                    let receiver = Arc::clone(&receiver);
+                    scope.spawn(move || count_chunks(&receiver, counters, is_safe, first_error))
+                })
+                .collect();
+            // Only the workers hold the receiver, so if they all panic the
+            // reader's sends fail instead of blocking on a full channel.
+            drop(receiver);
+
+            read_chunks(reader, chunk_lines, sender, first_error);
+
+            let mut counts = vec![0; counters];
+            for worker in workers {
+                let worker_counts = worker
+                    .join()
+                    .unwrap_or_else(|err| panic::resume_unwind(err));
+                for (count, worker_count) in counts.iter_mut().zip(worker_counts) {
+                    *count += worker_count;
+                }
+            }
+            counts
+        });
+
+        match first_error.0.into_inner().unwrap() {
+            Some((_, err)) => Err(err),
+            None => Ok(counts),
+        }
+    }
+}
+
+fn read_chunks(
+    reader: impl BufRead,
+    chunk_lines: usize,
+    sender: SyncSender<Chunk>,
+    first_error: &FirstError,
+) {
+    let mut lines = reader.lines();
+    let mut next_line = 1;
+
+    // Nothing after an error can change the result, so stop reading.
+    while first_error.line().is_none() {
+        let mut chunk = Chunk {
+            first_line: next_line,
+            lines: Vec::with_capacity(chunk_lines),
+        };
+        for text in lines.by_ref().take(chunk_lines) {
+            match text {
+                Ok(text) => chunk.lines.push(text),
+                Err(error) => {
+                    let line = next_line + chunk.lines.len();
+                    first_error.record(line, StreamError::Io { line, error });
+                    break;
+                }
+            }
+        }
+
+        let full = chunk.lines.len() == chunk_lines;
+        next_line += chunk.lines.len();
+        if chunk.lines.is_empty() || sender.send(chunk).is_err() || !full {
+            break;
+        }
+    }
+}
+
+fn count_chunks(
+    receiver: &Mutex<Receiver<Chunk>>,
+    counters: usize,
+    is_safe: impl Fn(usize, &[u32]) -> bool,
+    first_error: &FirstError,
+) -> Vec<usize> {
+    let mut counts = vec![0; counters];
+
+    // Keep draining after an error so the reader never blocks on a full
+    // channel; only chunks before the error still need to be checked.
+    loop {
+        // Bound first so the lock is released before the chunk is checked.
+        let next = receiver.lock().unwrap().recv();
+        let Ok(chunk) = next else {
+            break;
+        };
+        if first_error
+            .line()
+            .is_some_and(|line| line < chunk.first_line)
+        {
+            continue;
+        }
+
+        for (offset, text) in chunk.lines.iter().enumerate() {
+            let line = Line {
+                day: Day02::DAY,
+                number: chunk.first_line + offset,
+                text,
+            };
+            match parse_report(&line) {
+                Ok(report) => {
+                    for (index, count) in counts.iter_mut().enumerate() {
+                        if is_safe(index, &report) {
+                            *count += 1;
+                        }
+                    }
+                }
+                Err(err) => {
+                    first_error.record(line.number, StreamError::Parse(err));
+                    break;
+                }
+            }
+        }
+    }
+    counts
+}
+
+#[test]
+fn test_matches_sequential() {
+    let mut rng = aoc_common::random::Lcg::new(0x2024);
+    let mut next = |bound| rng.below(bound);
+    let input: String = (0..3000)
+        .map(|_| {
+            let mut level = 50 + next(10);
+            let levels: Vec<String> = (0..5 + next(4))
+                .map(|_| {
+                    level = level + next(5) - 2;
+                    level.to_string()
+                })
+                .collect();
+            levels.join(" ") + "\n"
+        })
+        .collect();
+
+    let reports = Day02::parse(&input).unwrap();
+    let policies = [SafetyPolicy::PART1, SafetyPolicy::PART2];
+    let expected: Vec<usize> = policies
+        .iter()
+        .map(|policy| crate::count_safe(&reports, policy))
+        .collect();
+
+    let count = ParallelCount {
+        threads: 4,
+        chunk_lines: 7,
+    };
+    let counts = count.count_safe(input.as_bytes(), &policies).unwrap();
+    assert_eq!(counts, expected);
+}
+
+#[test]
+fn test_earliest_parse_error() {
+    let mut input = "1 2 3\n".repeat(40);
+    input.replace_range(4 * 6..4 * 6 + 5, "1 x 3");
+    input.replace_range(30 * 6..30 * 6 + 5, "1 2 y");
+
+    let count = ParallelCount {
+        threads: 3,
+        chunk_lines: 2,
+    };
+    let err = count
+        .count_safe(input.as_bytes(), &[SafetyPolicy::PART1])
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "day 2, line 5, column 3: invalid number `x`"
+    );
+}
+
+#[test]
+fn test_io_error_line() {
+    let err = ParallelCount::default()
+        .count_safe(&b"1 2 3\n4 5 6\n\xff\n"[..], &[SafetyPolicy::PART1])
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "unable to read line 3: stream did not contain valid UTF-8"
+    );
+}
+
+#[test]
+fn test_worker_panic_propagates() {
+    let input = "1 2 3\n".repeat(100);
+    let count = ParallelCount {
+        threads: 2,
+        chunk_lines: 1,
+    };
+    let result = panic::catch_unwind(|| {
+        count.count_matching(input.as_bytes(), 1, |_, _| panic!("policy failed"))
+    });
+    let payload = result.map(|_| ()).unwrap_err();
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"policy failed"));
+}
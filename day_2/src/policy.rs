@@ -0,0 +1,316 @@
+//! The rules that decide whether a report is safe.
+//!
+//! A policy can also be read from a config file of `key = value` lines, with
+//! `#` starting a comment:
+//!
+//! ```text
+//! min_step = 1
+//! max_step = 3
+//! directions = "either"
+//! allow_plateaus = false
+//! budget = 1
+//! ```
+//!
+//! Keys that are left out keep the value of the policy being updated.
+
+use std::{fs, path::Path};
+
+/// Which way the levels of a safe report may go.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Directions {
+    Increasing,
+    Decreasing,
+    Either,
+}
+
+impl Directions {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "increasing" => Some(Directions::Increasing),
+            "decreasing" => Some(Directions::Decreasing),
+            "either" => Some(Directions::Either),
+            _ => None,
+        }
+    }
+
+    fn trends(self) -> &'static [bool] {
+        match self {
+            Directions::Increasing => &[true],
+            Directions::Decreasing => &[false],
+            Directions::Either => &[true, false],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SafetyPolicy {
+    /// Smallest allowed difference between adjacent levels, at least 1.
+    pub min_step: u32,
+    pub max_step: u32,
+    pub directions: Directions,
+    /// Whether adjacent levels may be equal. A zero step is only ever
+    /// governed by this, never by `min_step`.
+    pub allow_plateaus: bool,
+    /// How many levels the Problem Dampener may remove.
+    pub budget: usize,
+}
+
+impl SafetyPolicy {
+    pub const PART1: Self = Self {
+        min_step: 1,
+        max_step: 3,
+        directions: Directions::Either,
+        allow_plateaus: false,
+        budget: 0,
+    };
+
+    pub const PART2: Self = Self {
+        budget: 1,
+        ..Self::PART1
+    };
+
+    pub fn preset(name: &str) -> Option<Self> {
+        match name {
+            "part1" => Some(Self::PART1),
+            "part2" => Some(Self::PART2),
+            _ => None,
+        }
+    }
+
+    /// Sets one field by its config file name, as `min_step`.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        let invalid = |expected: &str| format!("expected {expected} for `{key}`, found `{value}`");
+        match key {
+            "min_step" => self.min_step = value.parse().map_err(|_| invalid("a number"))?,
+            "max_step" => self.max_step = value.parse().map_err(|_| invalid("a number"))?,
+            "directions" => {
+                self.directions = Directions::from_name(value)
+                    .ok_or_else(|| invalid("`increasing`, `decreasing` or `either`"))?;
+            }
+            "allow_plateaus" => {
+                self.allow_plateaus = value.parse().map_err(|_| invalid("`true` or `false`"))?;
+            }
+            "budget" => self.budget = value.parse().map_err(|_| invalid("a number"))?,
+            _ => return Err(format!("unknown key `{key}`")),
+        }
+        Ok(())
+    }
+
+    /// Applies the settings of a config file on top of this policy. If any
+    /// line is invalid the policy is left as it was.
+    pub fn apply_config(&mut self, text: &str) -> Result<(), String> {
+        let mut updated = self.clone();
+        for (idx, line) in text.lines().enumerate() {
+            let line_error = |reason: String| format!("line {}: {reason}", idx + 1);
+            let line = line.split_once('#').map_or(line, |(line, _)| line).trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| line_error(format!("expected `key = value`, found `{line}`")))?;
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|value| value.strip_suffix('"'))
+                .unwrap_or(value);
+            updated.set(key.trim(), value).map_err(line_error)?;
+        }
+        *self = updated;
+        Ok(())
+    }
+
+    pub fn load_config(&mut self, path: &Path) -> Result<(), String> {
+        let text = fs::read_to_string(path)
+            .map_err(|err| format!("unable to read {}: {err}", path.display()))?;
+        self.apply_config(&text)
+            .map_err(|err| format!("{}: {err}", path.display()))
+    }
+
+    /// Checks the settings are consistent with each other.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.min_step == 0 {
+            return Err("min_step must be at least 1; use allow_plateaus for zero steps".into());
+        }
+        if self.min_step > self.max_step {
+            return Err(format!(
+                "min_step {} is larger than max_step {}",
+                self.min_step, self.max_step
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn is_safe(&self, report: &[u32]) -> bool {
+        self.dampen(report).is_some()
+    }
+
+    /// The fewest levels, at most `budget` of them, whose removal leaves a
+    /// safe report, as ascending indices, or `None` if more would have to go.
+    ///
+    /// Runs in O(n * budget): a kept level can only follow one of the
+    /// `budget + 1` levels before it, as everything in between is removed.
+    pub fn dampen(&self, report: &[u32]) -> Option<Vec<usize>> {
+        self.directions
+            .trends()
+            .iter()
+            .filter_map(|&increasing| self.dampen_trend(report, increasing))
+            .min_by_key(Vec::len)
+    }
+
+    fn safe_step(&self, from: u32, to: u32, increasing: bool) -> bool {
+        if from == to {
+            return self.allow_plateaus;
+        }
+        let (low, high) = if increasing { (from, to) } else { (to, from) };
+        high > low && (self.min_step..=self.max_step).contains(&(high - low))
+    }
+
+    fn dampen_trend(&self, report: &[u32], increasing: bool) -> Option<Vec<usize>> {
+        let budget = self.budget;
+        // removed[i] is the fewest removals among levels 0..=i that keep level
+        // i and leave the kept levels safe; previous[i] is the kept level
+        // before it.
+        let mut removed: Vec<Option<usize>> = vec![None; report.len()];
+        let mut previous: Vec<Option<usize>> = vec![None; report.len()];
+
+        for i in 0..report.len() {
+            if i <= budget {
+                removed[i] = Some(i);
+            }
+            for j in i.saturating_sub(budget + 1)..i {
+                let Some(before) = removed[j] else {
+                    continue;
+                };
+                let total = before + (i - j - 1);
+                // On a tie the later predecessor wins, so that the earliest
+                // levels are the ones removed, as the puzzle describes.
+                if total <= budget
+                    && removed[i].is_none_or(|best| total <= best)
+                    && self.safe_step(report[j], report[i], increasing)
+                {
+                    removed[i] = Some(total);
+                    previous[i] = Some(j);
+                }
+            }
+        }
+
+        let Some(last) = report.len().checked_sub(1) else {
+            return Some(Vec::new());
+        };
+        let (mut kept, _) = (0..=last)
+            .filter_map(|i| Some((i, removed[i]? + (last - i))))
+            .filter(|&(_, total)| total <= budget)
+            .min_by_key(|&(_, total)| total)?;
+
+        let mut keep = vec![false; report.len()];
+        loop {
+            keep[kept] = true;
+            match previous[kept] {
+                Some(before) => kept = before,
+                None => break,
+            }
+        }
+        Some((0..report.len()).filter(|&i| !keep[i]).collect())
+    }
+}
+
+#[cfg(test)]
+fn with_budget(budget: usize) -> SafetyPolicy {
+    SafetyPolicy {
+        budget,
+        ..SafetyPolicy::PART1
+    }
+}
+
+#[test]
+fn test_dampen_indices() {
+    assert_eq!(with_budget(0).dampen(&[7, 6, 4, 2, 1]), Some(vec![]));
+    assert_eq!(with_budget(1).dampen(&[9, 6, 5, 7]), Some(vec![3]));
+    assert_eq!(with_budget(1).dampen(&[1, 2, 7, 8, 9]), None);
+    assert_eq!(with_budget(2).dampen(&[1, 2, 7, 8, 9]), Some(vec![0, 1]));
+    assert_eq!(with_budget(0).dampen(&[]), Some(vec![]));
+}
+
+#[test]
+fn test_dampen_matches_brute_force() {
+    fn fewest_removals(report: &[u32], budget: usize) -> Option<usize> {
+        if with_budget(0).is_safe(report) {
+            return Some(0);
+        }
+        if budget == 0 {
+            return None;
+        }
+        (0..report.len())
+            .filter_map(|i| {
+                let mut altered_report = report.to_vec();
+                altered_report.remove(i);
+                fewest_removals(&altered_report, budget - 1)
+            })
+            .min()
+            .map(|removed| removed + 1)
+    }
+
+    let mut rng = aoc_common::random::Lcg::new(0x2024);
+    let mut next = |bound| rng.below(bound);
+    for _ in 0..500 {
+        let len = next(8);
+        let report: Vec<u32> = (0..len).map(|_| next(10)).collect();
+        for budget in 0..3 {
+            let removed = with_budget(budget).dampen(&report);
+            assert_eq!(
+                removed.as_ref().map(Vec::len),
+                fewest_removals(&report, budget),
+                "{report:?} with budget {budget}"
+            );
+            if let Some(removed) = removed {
+                let kept: Vec<u32> = (0..report.len())
+                    .filter(|i| !removed.contains(i))
+                    .map(|i| report[i])
+                    .collect();
+                assert!(
+                    with_budget(0).is_safe(&kept),
+                    "{report:?} minus {removed:?}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_config() {
+    let mut policy = SafetyPolicy::PART2;
+    policy
+        .apply_config(
+            "# Gentle slopes only\n\
+             max_step = 2\n\
+             directions = \"increasing\" # no descents\n\
+             \n\
+             allow_plateaus = true\n",
+        )
+        .unwrap();
+
+    assert_eq!(
+        policy,
+        SafetyPolicy {
+            min_step: 1,
+            max_step: 2,
+            directions: Directions::Increasing,
+            allow_plateaus: true,
+            budget: 1,
+        }
+    );
+    assert!(policy.is_safe(&[1, 1, 3, 9, 4]));
+    assert!(!policy.is_safe(&[5, 4, 3]));
+
+    let before = policy.clone();
+    let err = policy.apply_config("budget = 4\nbudget: 2").unwrap_err();
+    assert_eq!(err, "line 2: expected `key = value`, found `budget: 2`");
+    assert_eq!(policy, before);
+    let err = policy.apply_config("directions = up").unwrap_err();
+    assert_eq!(
+        err,
+        "line 1: expected `increasing`, `decreasing` or `either` for `directions`, found `up`"
+    );
+}
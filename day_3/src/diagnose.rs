@@ -0,0 +1,92 @@
+//! Near-miss instructions, such as a `mul(` that never closes, for
+//! `day03 --diagnose`.
+
+use std::fmt;
+
+use crate::{
+    interpreter::InstructionSet,
+    scanner::{Event, InvalidInstruction, NearMiss, Scanner},
+};
+
+/// Bytes of context shown either side of a near miss.
+const CONTEXT: usize = 8;
+
+pub struct Diagnosis<'a> {
+    input: &'a str,
+    pub misses: Vec<NearMiss>,
+}
+
+impl<'a> Diagnosis<'a> {
+    /// The near misses of the instructions of `set` in `input`.
+    pub fn new(input: &'a str, set: &InstructionSet) -> Self {
+        let mut scanner = Scanner::new(set);
+        let mut misses = Vec::new();
+        for byte in input.bytes() {
+            scanner.step(byte, |event| {
+                if let Event::NearMiss(miss) = event {
+                    misses.push(miss);
+                }
+            });
+        }
+        misses.extend(scanner.finish());
+        Self { input, misses }
+    }
+
+    pub fn count(&self, error: InvalidInstruction) -> usize {
+        self.misses
+            .iter()
+            .filter(|miss| miss.error == error)
+            .count()
+    }
+
+    /// The text of the near miss up to and including the byte that broke it,
+    /// with some context either side, escaped to fit on one line.
+    pub fn snippet(&self, miss: &NearMiss) -> String {
+        let bytes = self.input.as_bytes();
+        let start = miss.start.saturating_sub(CONTEXT);
+        let end = (miss.offset + 1 + CONTEXT).min(bytes.len());
+        String::from_utf8_lossy(&bytes[start..end])
+            .escape_debug()
+            .to_string()
+    }
+}
+
+impl fmt::Display for Diagnosis<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for miss in &self.misses {
+            writeln!(
+                f,
+                "{:>8}  {:<23}  `{}`",
+                miss.start,
+                miss.error,
+                self.snippet(miss)
+            )?;
+        }
+
+        writeln!(f, "Near misses: {}", self.misses.len())?;
+        for error in InvalidInstruction::ALL {
+            writeln!(f, "  {error}: {}", self.count(error))?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_diagnose() {
+    let input = "mul(2,4)mul(+4,5)mul(4;5)mul(1234,5)xxmul(6,7]mul(8,";
+    let diagnosis = Diagnosis::new(input, &InstructionSet::part2());
+
+    assert_eq!(
+        diagnosis.to_string(),
+        "       8  UnparsableLeftOperand    `mul(2,4)mul(+4,5)mul(`\n      \
+         17  MissingComma             `ul(+4,5)mul(4;5)mul(12`\n      \
+         25  UnparsableLeftOperand    `mul(4;5)mul(1234,5)xxmul`\n      \
+         38  MissingRightParenthesis  `234,5)xxmul(6,7]mul(8,`\n      \
+         46  UnparsableRightOperand   `mul(6,7]mul(8,`\n\
+         Near misses: 5\n  \
+         MissingComma: 1\n  \
+         UnparsableLeftOperand: 2\n  \
+         UnparsableRightOperand: 1\n  \
+         MissingRightParenthesis: 1\n"
+    );
+}
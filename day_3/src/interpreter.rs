@@ -0,0 +1,383 @@
+//! Runs the instructions found in corrupted memory. Each opcode is an
+//! [`Instruction`] registered in an [`InstructionSet`], which is also what
+//! the [`Scanner`](crate::scanner::Scanner) looks for, so new ones can be
+//! added without touching the tokenizer. The two parts of the puzzle are
+//! just different built-in sets.
+
+use std::{error::Error, fmt};
+
+use crate::lexer::{Lexer, Spanned, Token};
+
+/// What instructions act on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Machine {
+    /// Signed because `sub` can take it below zero, and wide enough that no
+    /// real memory dump can overflow it, but checked all the same.
+    pub accumulator: i128,
+    /// Whether value-producing instructions currently count.
+    pub enabled: bool,
+}
+
+impl Default for Machine {
+    fn default() -> Self {
+        Self {
+            accumulator: 0,
+            enabled: true,
+        }
+    }
+}
+
+/// An instruction's arithmetic did not fit in an `i128`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow;
+
+impl Machine {
+    /// Adds `value` to the accumulator if the machine is enabled.
+    pub fn accumulate(&mut self, value: i128) -> Result<(), Overflow> {
+        if self.enabled {
+            self.accumulator = self.accumulator.checked_add(value).ok_or(Overflow)?;
+        }
+        Ok(())
+    }
+}
+
+/// An [`Overflow`] tied to the instruction that caused it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverflowError {
+    /// Byte offset of the instruction in the input.
+    pub offset: usize,
+    pub instruction: String,
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "arithmetic overflow in `{}` at byte {}",
+            self.instruction, self.offset
+        )
+    }
+}
+
+impl Error for OverflowError {}
+
+/// An opcode written as `name(a,b,...)`, where every operand is 1 to 3
+/// digits.
+pub trait Instruction {
+    fn name(&self) -> &'static str;
+    fn arity(&self) -> usize;
+    fn execute(&self, machine: &mut Machine, operands: &[u32]) -> Result<(), Overflow>;
+}
+
+fn product(a: u32, b: u32) -> Result<i128, Overflow> {
+    i128::from(a).checked_mul(i128::from(b)).ok_or(Overflow)
+}
+
+/// `mul(a,b)` adds `a * b`.
+pub struct Mul;
+/// `do()` enables the machine.
+pub struct Do;
+/// `don't()` disables the machine.
+pub struct Dont;
+/// `add(a,b)` adds `a + b`.
+pub struct Add;
+/// `sub(a,b)` adds `a - b`, which may be negative.
+pub struct Sub;
+/// `div(a,b)` adds `a / b` rounded down, or nothing when `b` is zero.
+pub struct Div;
+/// `reset()` clears the accumulator if the machine is enabled.
+pub struct Reset;
+
+impl Instruction for Mul {
+    fn name(&self) -> &'static str {
+        "mul"
+    }
+
+    fn arity(&self) -> usize {
+        2
+    }
+
+    fn execute(&self, machine: &mut Machine, operands: &[u32]) -> Result<(), Overflow> {
+        machine.accumulate(product(operands[0], operands[1])?)
+    }
+}
+
+impl Instruction for Do {
+    fn name(&self) -> &'static str {
+        "do"
+    }
+
+    fn arity(&self) -> usize {
+        0
+    }
+
+    fn execute(&self, machine: &mut Machine, _: &[u32]) -> Result<(), Overflow> {
+        machine.enabled = true;
+        Ok(())
+    }
+}
+
+impl Instruction for Dont {
+    fn name(&self) -> &'static str {
+        "don't"
+    }
+
+    fn arity(&self) -> usize {
+        0
+    }
+
+    fn execute(&self, machine: &mut Machine, _: &[u32]) -> Result<(), Overflow> {
+        machine.enabled = false;
+        Ok(())
+    }
+}
+
+impl Instruction for Add {
+    fn name(&self) -> &'static str {
+        "add"
+    }
+
+    fn arity(&self) -> usize {
+        2
+    }
+
+    fn execute(&self, machine: &mut Machine, operands: &[u32]) -> Result<(), Overflow> {
+        machine.accumulate(i128::from(operands[0]) + i128::from(operands[1]))
+    }
+}
+
+impl Instruction for Sub {
+    fn name(&self) -> &'static str {
+        "sub"
+    }
+
+    fn arity(&self) -> usize {
+        2
+    }
+
+    fn execute(&self, machine: &mut Machine, operands: &[u32]) -> Result<(), Overflow> {
+        machine.accumulate(i128::from(operands[0]) - i128::from(operands[1]))
+    }
+}
+
+impl Instruction for Div {
+    fn name(&self) -> &'static str {
+        "div"
+    }
+
+    fn arity(&self) -> usize {
+        2
+    }
+
+    fn execute(&self, machine: &mut Machine, operands: &[u32]) -> Result<(), Overflow> {
+        match operands[0].checked_div(operands[1]) {
+            Some(quotient) => machine.accumulate(i128::from(quotient)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Instruction for Reset {
+    fn name(&self) -> &'static str {
+        "reset"
+    }
+
+    fn arity(&self) -> usize {
+        0
+    }
+
+    fn execute(&self, machine: &mut Machine, _: &[u32]) -> Result<(), Overflow> {
+        if machine.enabled {
+            machine.accumulator = 0;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct InstructionSet {
+    instructions: Vec<Box<dyn Instruction>>,
+}
+
+impl InstructionSet {
+    /// Part 1: only `mul` is understood.
+    pub fn part1() -> Self {
+        Self::default().with(Mul)
+    }
+
+    /// Part 2: `mul`, switched on and off by `do()` and `don't()`.
+    pub fn part2() -> Self {
+        Self::part1().with(Do).with(Dont)
+    }
+
+    /// Part 2 plus `add`, `sub`, `div` and `reset`.
+    pub fn extended() -> Self {
+        Self::part2().with(Add).with(Sub).with(Div).with(Reset)
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "part1" => Some(Self::part1()),
+            "part2" => Some(Self::part2()),
+            "extended" => Some(Self::extended()),
+            _ => None,
+        }
+    }
+
+    /// Registers another instruction. A later instruction with the same name
+    /// replaces the earlier one.
+    ///
+    /// # Panics
+    ///
+    /// If the name is empty or contains a parenthesis, which the scanner
+    /// relies on never happening.
+    pub fn with(mut self, instruction: impl Instruction + 'static) -> Self {
+        let name = instruction.name();
+        assert!(
+            !name.is_empty() && !name.contains(['(', ')']),
+            "invalid instruction name `{name}`"
+        );
+        self.instructions
+            .retain(|existing| existing.name() != instruction.name());
+        self.instructions.push(Box::new(instruction));
+        self
+    }
+
+    /// The instructions in the order they were registered.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Instruction> {
+        self.instructions.iter().map(Box::as_ref)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&dyn Instruction> {
+        self.iter().find(|instruction| instruction.name() == name)
+    }
+
+    /// The instruction that runs `token`: the one of the same name, if its
+    /// arity matches.
+    pub fn for_token(&self, token: &Token) -> Option<&dyn Instruction> {
+        self.get(token.name)
+            .filter(|instruction| instruction.arity() == token.operands.len())
+    }
+
+    /// Runs a token from the [`Lexer`], ignoring it unless this set has an
+    /// instruction for it.
+    pub fn execute_token(
+        &self,
+        machine: &mut Machine,
+        spanned: &Spanned,
+    ) -> Result<(), OverflowError> {
+        let token = &spanned.token;
+        match self.for_token(token) {
+            Some(instruction) => {
+                instruction
+                    .execute(machine, &token.operands)
+                    .map_err(|Overflow| OverflowError {
+                        offset: spanned.span.start,
+                        instruction: token.to_string(),
+                    })
+            }
+            None => Ok(()),
+        }
+    }
+
+    pub fn run_tokens(&self, tokens: &[Spanned]) -> Result<Machine, OverflowError> {
+        let mut machine = Machine::default();
+        for spanned in tokens {
+            self.execute_token(&mut machine, spanned)?;
+        }
+        Ok(machine)
+    }
+
+    /// Finds and runs every instruction of this set in `input`, skipping
+    /// everything else.
+    pub fn run(&self, input: &str) -> Result<Machine, OverflowError> {
+        let mut machine = Machine::default();
+        for spanned in Lexer::new(input, self) {
+            self.execute_token(&mut machine, &spanned)?;
+        }
+        Ok(machine)
+    }
+}
+
+#[test]
+fn test_built_in_sets_share_part2_tokens() {
+    let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))\
+                 mul(mul(1,2)do(don't()mul(1234,5)mul(3,4)";
+    // As in `Day03::parse`.
+    let tokens: Vec<Spanned> = Lexer::new(input, &InstructionSet::part2()).collect();
+    for set in [InstructionSet::part1(), InstructionSet::part2()] {
+        assert_eq!(set.run(input), set.run_tokens(&tokens));
+    }
+    assert_eq!(
+        InstructionSet::part1().run(input).unwrap().accumulator,
+        161 + 2 + 12
+    );
+    assert_eq!(
+        InstructionSet::part2().run(input).unwrap().accumulator,
+        48 + 2
+    );
+}
+
+#[test]
+fn test_extended_set() {
+    let input = "add(1,2)sub(1,10)div(7,2)div(1,0)don't()add(5,5)do()reset()mul(2,3)sub(9,1)";
+    let set = InstructionSet::extended();
+    let machine = set.run(input).unwrap();
+    assert_eq!(machine.accumulator, 14);
+
+    // Streaming finds the same instructions.
+    let mut streamed = Machine::default();
+    crate::scanner::scan(input.as_bytes(), 3, &set, |spanned| {
+        set.execute_token(&mut streamed, &spanned).unwrap();
+    })
+    .unwrap();
+    assert_eq!(streamed, machine);
+
+    struct Square;
+    impl Instruction for Square {
+        fn name(&self) -> &'static str {
+            "sq"
+        }
+
+        fn arity(&self) -> usize {
+            1
+        }
+
+        fn execute(&self, machine: &mut Machine, operands: &[u32]) -> Result<(), Overflow> {
+            machine.accumulate(i128::from(operands[0]).pow(2))
+        }
+    }
+    let machine = InstructionSet::part1()
+        .with(Square)
+        .run("sq(12)mul(2,3)sq(1,2)")
+        .unwrap();
+    assert_eq!(machine.accumulator, 150);
+}
+
+#[test]
+fn test_overflow_names_instruction() {
+    /// `max()` pushes the accumulator to its limit.
+    struct Max;
+    impl Instruction for Max {
+        fn name(&self) -> &'static str {
+            "max"
+        }
+
+        fn arity(&self) -> usize {
+            0
+        }
+
+        fn execute(&self, machine: &mut Machine, _: &[u32]) -> Result<(), Overflow> {
+            machine.accumulate(i128::MAX - machine.accumulator)
+        }
+    }
+
+    let set = InstructionSet::part2().with(Max);
+    let err = set.run("mul(1,1)max()xmul(2,3)").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "arithmetic overflow in `mul(2,3)` at byte 14"
+    );
+    // Disabled instructions cannot overflow.
+    assert!(set.run("max()don't()mul(2,3)").is_ok());
+}
@@ -0,0 +1,120 @@
+//! Tokenizer for the corrupted memory. Everything that is not an intact
+//! instruction of the [`InstructionSet`] being lexed is skipped.
+
+use std::{fmt, ops::Range, slice};
+
+use crate::{interpreter::InstructionSet, scanner::Scanner};
+
+/// An instruction as written in the input, `name(a,b,...)`, where every
+/// operand is 1 to 3 digits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub name: &'static str,
+    pub operands: Vec<u32>,
+}
+
+impl Token {
+    pub fn new(name: &'static str, operands: &[u32]) -> Self {
+        Self {
+            name,
+            operands: operands.to_vec(),
+        }
+    }
+}
+
+/// Writes the token as it appears in the input.
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}(", self.name)?;
+        for (idx, operand) in self.operands.iter().enumerate() {
+            if idx > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{operand}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// A token with the byte range of its text in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Spanned {
+    pub token: Token,
+    pub span: Range<usize>,
+}
+
+/// Yields the tokens of a whole input held in memory. See [`Scanner`] for
+/// input that arrives in chunks.
+pub struct Lexer<'a> {
+    bytes: slice::Iter<'a, u8>,
+    scanner: Scanner,
+}
+
+impl<'a> Lexer<'a> {
+    pub fn new(input: &'a str, set: &InstructionSet) -> Self {
+        Self {
+            bytes: input.as_bytes().iter(),
+            scanner: Scanner::new(set),
+        }
+    }
+}
+
+impl Iterator for Lexer<'_> {
+    type Item = Spanned;
+
+    fn next(&mut self) -> Option<Spanned> {
+        self.bytes
+            .by_ref()
+            .find_map(|&byte| self.scanner.push(byte))
+    }
+}
+
+#[cfg(test)]
+fn tokens(input: &str) -> Vec<Token> {
+    Lexer::new(input, &InstructionSet::part2())
+        .map(|spanned| spanned.token)
+        .collect()
+}
+
+#[cfg(test)]
+fn mul(a: u32, b: u32) -> Token {
+    Token::new("mul", &[a, b])
+}
+
+#[test]
+fn test_lex_example() {
+    let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+    let spans: Vec<(Token, &str)> = Lexer::new(input, &InstructionSet::part2())
+        .map(|spanned| (spanned.token, &input[spanned.span]))
+        .collect();
+
+    assert_eq!(
+        spans,
+        [
+            (mul(2, 4), "mul(2,4)"),
+            (Token::new("don't", &[]), "don't()"),
+            (mul(5, 5), "mul(5,5)"),
+            (mul(11, 8), "mul(11,8)"),
+            (Token::new("do", &[]), "do()"),
+            (mul(8, 5), "mul(8,5)"),
+        ]
+    );
+}
+
+#[test]
+fn test_lex_rejects_malformed_mul() {
+    assert_eq!(tokens("mul(+4,5)mul(4,-5)mul(4 ,5)mul ( 2,4)"), []);
+    assert_eq!(tokens("mul(1234,5)mul(5,1234)mul(,5)mul(5,)"), []);
+    assert_eq!(tokens("mul(999,100)"), [mul(999, 100)]);
+}
+
+#[test]
+fn test_lex_restarts_inside_failed_match() {
+    let input = "mul(mul(2,3)do(don't()";
+    let spans: Vec<_> = Lexer::new(input, &InstructionSet::part2())
+        .map(|spanned| spanned.span)
+        .collect();
+
+    assert_eq!(tokens(input), [mul(2, 3), Token::new("don't", &[])]);
+    assert_eq!(spans, [4..12, 15..22]);
+}
@@ -0,0 +1,79 @@
+use aoc_common::{Answer, ParseError, Part, Solution, SolveError};
+
+use crate::{
+    interpreter::InstructionSet,
+    lexer::{Lexer, Spanned},
+};
+
+pub mod diagnose;
+pub mod interpreter;
+pub mod lexer;
+pub mod scanner;
+pub mod trace;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Parsed = Vec<Spanned>;
+
+    /// Part 2's instructions include part 1's, so its tokens serve both.
+    fn parse(input: &str) -> Result<Vec<Spanned>, ParseError> {
+        Ok(Lexer::new(input, &InstructionSet::part2()).collect())
+    }
+
+    fn part1(tokens: &Vec<Spanned>) -> Result<Answer, SolveError> {
+        run(Part::One, &InstructionSet::part1(), tokens)
+    }
+
+    fn part2(tokens: &Vec<Spanned>) -> Result<Answer, SolveError> {
+        run(Part::Two, &InstructionSet::part2(), tokens)
+    }
+}
+
+fn run(part: Part, set: &InstructionSet, tokens: &[Spanned]) -> Result<Answer, SolveError> {
+    let error = |reason: String| SolveError::new(Day03::DAY, part, reason);
+    let machine = set
+        .run_tokens(tokens)
+        .map_err(|err| error(err.to_string()))?;
+    u64::try_from(machine.accumulator)
+        .map(Answer::Number)
+        .map_err(|_| {
+            let mut reason = format!("total {} does not fit in an answer", machine.accumulator);
+            if let Some(last) = tokens
+                .iter()
+                .rev()
+                .find(|spanned| set.for_token(&spanned.token).is_some())
+            {
+                reason += &format!(" after `{}` at byte {}", last.token, last.span.start);
+            }
+            error(reason)
+        })
+}
+
+#[test]
+fn test_parts_example() {
+    let tokens =
+        Day03::parse("xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))")
+            .unwrap();
+
+    assert_eq!(Day03::part1(&tokens), Ok(Answer::Number(161)));
+    assert_eq!(Day03::part2(&tokens), Ok(Answer::Number(48)));
+}
+
+#[test]
+fn test_total_out_of_range() {
+    let set = InstructionSet::extended();
+    let input = "mul(2,3)sub(1,9)xmul(1,1)";
+    let tokens: Vec<Spanned> = Lexer::new(input, &set).collect();
+
+    assert_eq!(
+        run(Part::Two, &set, &tokens),
+        Err(SolveError::new(
+            3,
+            Part::Two,
+            "total -1 does not fit in an answer after `mul(1,1)` at byte 17"
+        ))
+    );
+}
@@ -0,0 +1,187 @@
+use std::process::ExitCode;
+
+use aoc_common::{
+    input::Source,
+    runner::{self, Args, Modes},
+};
+use day03::{
+    diagnose::Diagnosis,
+    interpreter::{InstructionSet, Machine},
+    lexer::{Lexer, Spanned},
+    scanner::scan,
+    trace::{table_header, trace},
+    Day03,
+};
+
+const USAGE: &str = "\
+Usage:
+    day03 [--input <PATH> | --example]
+    day03 --instructions <SET> [--input <PATH> | --example]
+    day03 --stream [--chunk-size <BYTES>] [--instructions <SET>]
+          [--input <PATH> | --example]
+    day03 --diagnose [--instructions <SET>] [--input <PATH> | --example]
+    day03 --trace [--skipped] [--instructions <SET>] [--input <PATH> | --example]
+
+--instructions picks the instruction set <SET>: `part1` (mul), `part2`
+(mul, do, don't) or `extended` (part2 plus add, sub, div and reset). On
+its own it runs <SET> and prints the accumulator.
+
+--stream solves both parts, or runs <SET> if given, reading <BYTES>
+(default 65536) at a time, so memory use stays constant however large the
+input is.
+
+--diagnose lists every instruction of <SET> (default part2) that starts
+but is not valid, such as a `mul(` that never closes, with its byte
+offset, why it is invalid and the text around it, then counts them by
+reason.
+
+--trace lists every instruction <SET> (default part2) runs with its byte
+offset, whether it was reached enabled or disabled, the value it adds and
+the running total. With --skipped only the instructions whose values did
+not count are listed.";
+
+enum Mode {
+    Run(InstructionSet),
+    Stream {
+        chunk_size: usize,
+        set: Option<InstructionSet>,
+    },
+    Diagnose(InstructionSet),
+    Trace {
+        set: InstructionSet,
+        skipped: bool,
+    },
+}
+
+impl Modes for Mode {
+    fn usage(_: u8) -> String {
+        USAGE.to_string()
+    }
+
+    fn parse(args: &mut Args<'_>) -> Result<Option<Self>, String> {
+        let mut stream = false;
+        let mut diagnose = false;
+        let mut instructions = None;
+        let mut trace = false;
+        let mut skipped = false;
+        let mut chunk_size = None;
+
+        while let Some(arg) = args.next_arg()? {
+            match arg {
+                "--stream" => stream = true,
+                "--diagnose" => diagnose = true,
+                "--trace" => trace = true,
+                "--skipped" => skipped = true,
+                "--instructions" => {
+                    let value = args.value(arg)?;
+                    instructions = Some(
+                        InstructionSet::from_name(value)
+                            .ok_or_else(|| format!("Unknown instruction set `{value}`"))?,
+                    );
+                }
+                "--chunk-size" => {
+                    let value = args.value(arg)?;
+                    chunk_size = Some(
+                        value
+                            .parse()
+                            .ok()
+                            .filter(|&size| size > 0)
+                            .ok_or_else(|| format!("Invalid chunk size `{value}`"))?,
+                    );
+                }
+                other => return Err(format!("Unknown argument `{other}`")),
+            }
+        }
+
+        runner::exclusive(&[
+            ("--stream", stream),
+            ("--diagnose", diagnose),
+            ("--trace", trace),
+        ])?;
+        runner::requires(("--skipped", skipped), ("--trace", trace))?;
+        runner::requires(("--chunk-size", chunk_size.is_some()), ("--stream", stream))?;
+
+        let mode = if trace {
+            Some(Mode::Trace {
+                set: instructions.unwrap_or_else(InstructionSet::part2),
+                skipped,
+            })
+        } else if diagnose {
+            Some(Mode::Diagnose(
+                instructions.unwrap_or_else(InstructionSet::part2),
+            ))
+        } else if stream {
+            Some(Mode::Stream {
+                chunk_size: chunk_size.unwrap_or(1 << 16),
+                set: instructions,
+            })
+        } else {
+            instructions.map(Mode::Run)
+        };
+        Ok(mode)
+    }
+
+    fn run(self, source: &Source) -> Result<(), String> {
+        match self {
+            Mode::Run(set) => {
+                let input = source.read().map_err(|err| err.to_string())?;
+                let machine = set.run(&input).map_err(|err| err.to_string())?;
+                println!("Accumulator: {}", machine.accumulator);
+            }
+            Mode::Stream { chunk_size, set } => {
+                let both_parts = set.is_none();
+                let sets = match set {
+                    Some(set) => vec![set],
+                    None => vec![InstructionSet::part1(), InstructionSet::part2()],
+                };
+                // Scanned as in `Day03::parse`.
+                let tokens_of = sets.last().unwrap();
+                let reader = source.open().map_err(|err| err.to_string())?;
+                let mut machines = vec![Machine::default(); sets.len()];
+                let mut overflow = None;
+                scan(reader, chunk_size, tokens_of, |spanned| {
+                    // After an overflow the totals are meaningless, so the rest of
+                    // the input is only read through.
+                    if overflow.is_some() {
+                        return;
+                    }
+                    for (set, machine) in sets.iter().zip(&mut machines) {
+                        if let Err(err) = set.execute_token(machine, &spanned) {
+                            overflow = Some(err);
+                            return;
+                        }
+                    }
+                })
+                .map_err(|err| format!("unable to read {source}: {err}"))?;
+                if let Some(err) = overflow {
+                    return Err(err.to_string());
+                }
+                if both_parts {
+                    println!("Part 1: {}", machines[0].accumulator);
+                    println!("Part 2: {}", machines[1].accumulator);
+                } else {
+                    println!("Accumulator: {}", machines[0].accumulator);
+                }
+            }
+            Mode::Diagnose(set) => {
+                let input = source.read().map_err(|err| err.to_string())?;
+                print!("{}", Diagnosis::new(&input, &set));
+            }
+            Mode::Trace { set, skipped } => {
+                let input = source.read().map_err(|err| err.to_string())?;
+                let tokens: Vec<Spanned> = Lexer::new(&input, &set).collect();
+                println!("{}", table_header());
+                for step in trace(&set, &tokens).map_err(|err| err.to_string())? {
+                    if !skipped || step.skipped() {
+                        println!("{step}");
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn main() -> ExitCode {
+    runner::main_with::<Day03, Mode>()
+}
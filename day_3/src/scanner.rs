@@ -0,0 +1,462 @@
+//! A byte-at-a-time matcher for the instructions of an [`InstructionSet`],
+//! so input can be fed in chunks of any size.
+//!
+//! Every instruction whose name starts with a byte is followed from there,
+//! alongside the matches already open, so no byte is ever looked at twice.
+//! Names never contain parentheses, so the `)` that completes an instruction
+//! either completes or breaks every other open match too. The leftmost match
+//! wins, ties going to the instruction registered first, which is the same
+//! choice as trying each instruction in turn at each offset.
+
+use std::{
+    fmt,
+    io::{self, ErrorKind, Read},
+};
+
+use crate::{
+    interpreter::InstructionSet,
+    lexer::{Spanned, Token},
+};
+
+/// Up to three digits of an operand.
+#[derive(Debug, Clone, Copy, Default)]
+struct Operand {
+    value: u32,
+    digits: u8,
+}
+
+impl Operand {
+    fn push(self, byte: u8) -> Option<Self> {
+        (self.digits < 3).then(|| Self {
+            value: self.value * 10 + u32::from(byte - b'0'),
+            digits: self.digits + 1,
+        })
+    }
+}
+
+/// The shape of an instruction, `name(a,b,...)` with `arity` operands.
+#[derive(Debug, Clone, Copy)]
+struct Signature {
+    name: &'static str,
+    arity: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum State {
+    /// Matched this many bytes of the name, then `(` is expected.
+    Name(usize),
+    /// Matched `name(` of an instruction without operands.
+    Close,
+    /// Reading the operand after those already matched.
+    Operand(Operand),
+}
+
+enum Outcome {
+    Open,
+    Done,
+    Fail(Option<InvalidInstruction>),
+}
+
+/// A match of one instruction that is still open.
+#[derive(Debug, Clone)]
+struct Partial {
+    /// Index into the scanner's signatures.
+    signature: usize,
+    /// Offset of the first byte.
+    start: usize,
+    state: State,
+    operands: Vec<u32>,
+}
+
+impl Partial {
+    fn advance(&mut self, signature: Signature, byte: u8) -> Outcome {
+        let name = signature.name.as_bytes();
+        match self.state {
+            State::Name(matched) if matched < name.len() => {
+                if name[matched] != byte {
+                    return Outcome::Fail(None);
+                }
+                self.state = State::Name(matched + 1);
+            }
+            State::Name(_) if byte == b'(' => {
+                self.state = if signature.arity == 0 {
+                    State::Close
+                } else {
+                    State::Operand(Operand::default())
+                };
+            }
+            State::Name(_) => return Outcome::Fail(None),
+            State::Close if byte == b')' => return Outcome::Done,
+            State::Close => return Outcome::Fail(None),
+            State::Operand(operand) => {
+                let last = self.operands.len() + 1 == signature.arity;
+                match byte {
+                    b'0'..=b'9' => match operand.push(byte) {
+                        Some(operand) => self.state = State::Operand(operand),
+                        None => return Outcome::Fail(self.classify(signature, Some(byte))),
+                    },
+                    b',' if operand.digits > 0 && !last => {
+                        self.operands.push(operand.value);
+                        self.state = State::Operand(Operand::default());
+                    }
+                    b')' if operand.digits > 0 && last => {
+                        self.operands.push(operand.value);
+                        return Outcome::Done;
+                    }
+                    _ => return Outcome::Fail(self.classify(signature, Some(byte))),
+                }
+            }
+        }
+        Outcome::Open
+    }
+
+    /// Why the match cannot continue with `byte`, or `None` for the end of
+    /// the input. Only matches that got past their `(` are near misses.
+    fn classify(&self, signature: Signature, byte: Option<u8>) -> Option<InvalidInstruction> {
+        let State::Operand(operand) = self.state else {
+            return None;
+        };
+        let digit = byte.is_some_and(|byte| byte.is_ascii_digit());
+        let index = self.operands.len();
+        Some(if operand.digits == 0 || digit {
+            if index == 0 {
+                InvalidInstruction::UnparsableLeftOperand
+            } else {
+                InvalidInstruction::UnparsableRightOperand
+            }
+        } else if index + 1 < signature.arity {
+            InvalidInstruction::MissingComma
+        } else {
+            InvalidInstruction::MissingRightParenthesis
+        })
+    }
+}
+
+/// Why text that began like an instruction with operands, as `mul(`, is
+/// not one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InvalidInstruction {
+    MissingComma,
+    UnparsableLeftOperand,
+    UnparsableRightOperand,
+    MissingRightParenthesis,
+}
+
+impl InvalidInstruction {
+    pub const ALL: [Self; 4] = [
+        InvalidInstruction::MissingComma,
+        InvalidInstruction::UnparsableLeftOperand,
+        InvalidInstruction::UnparsableRightOperand,
+        InvalidInstruction::MissingRightParenthesis,
+    ];
+}
+
+impl fmt::Display for InvalidInstruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            InvalidInstruction::MissingComma => "MissingComma",
+            InvalidInstruction::UnparsableLeftOperand => "UnparsableLeftOperand",
+            InvalidInstruction::UnparsableRightOperand => "UnparsableRightOperand",
+            InvalidInstruction::MissingRightParenthesis => "MissingRightParenthesis",
+        })
+    }
+}
+
+/// An instruction with operands that got as far as its `(` but did not
+/// complete.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NearMiss {
+    /// Offset of the first byte of the name.
+    pub start: usize,
+    /// Offset of the byte that broke the instruction, or the input length if
+    /// the input ended first.
+    pub offset: usize,
+    pub error: InvalidInstruction,
+}
+
+pub enum Event {
+    Token(Spanned),
+    NearMiss(NearMiss),
+}
+
+pub struct Scanner {
+    signatures: Vec<Signature>,
+    partials: Vec<Partial>,
+    /// Offset of the next byte.
+    offset: usize,
+}
+
+impl Scanner {
+    pub fn new(set: &InstructionSet) -> Self {
+        Self {
+            signatures: set
+                .iter()
+                .map(|instruction| Signature {
+                    name: instruction.name(),
+                    arity: instruction.arity(),
+                })
+                .collect(),
+            partials: Vec::new(),
+            offset: 0,
+        }
+    }
+
+    /// Feeds one byte, returning the token it completes, if any.
+    pub fn push(&mut self, byte: u8) -> Option<Spanned> {
+        let mut token = None;
+        self.step(byte, |event| {
+            if let Event::Token(spanned) = event {
+                token = Some(spanned);
+            }
+        });
+        token
+    }
+
+    /// Feeds one byte, passing the token it completes and the matches it
+    /// breaks to `emit`.
+    pub fn step(&mut self, byte: u8, mut emit: impl FnMut(Event)) {
+        let offset = self.offset;
+        self.offset += 1;
+
+        // Partials are kept in order of start, then of registration, so the
+        // first to complete is the one that wins.
+        let mut done = None;
+        let signatures = &self.signatures;
+        self.partials.retain_mut(|partial| {
+            match partial.advance(signatures[partial.signature], byte) {
+                Outcome::Open => return true,
+                Outcome::Done if done.is_none() => done = Some(partial.clone()),
+                Outcome::Done => (),
+                Outcome::Fail(error) => {
+                    if let Some(error) = error {
+                        emit(Event::NearMiss(NearMiss {
+                            start: partial.start,
+                            offset,
+                            error,
+                        }));
+                    }
+                }
+            }
+            false
+        });
+
+        if let Some(partial) = done {
+            self.partials.clear();
+            emit(Event::Token(Spanned {
+                token: Token {
+                    name: self.signatures[partial.signature].name,
+                    operands: partial.operands,
+                },
+                span: partial.start..offset + 1,
+            }));
+            return;
+        }
+
+        for (index, signature) in self.signatures.iter().enumerate() {
+            if signature.name.as_bytes()[0] == byte {
+                self.partials.push(Partial {
+                    signature: index,
+                    start: offset,
+                    state: State::Name(1),
+                    operands: Vec::new(),
+                });
+            }
+        }
+    }
+
+    /// The instructions left incomplete when the input ends.
+    pub fn finish(&self) -> Vec<NearMiss> {
+        self.partials
+            .iter()
+            .filter_map(|partial| {
+                let error = partial.classify(self.signatures[partial.signature], None)?;
+                Some(NearMiss {
+                    start: partial.start,
+                    offset: self.offset,
+                    error,
+                })
+            })
+            .collect()
+    }
+
+    /// Feeds a chunk of input, passing every token it completes to `emit`.
+    pub fn feed(&mut self, chunk: &[u8], mut emit: impl FnMut(Spanned)) {
+        for &byte in chunk {
+            if let Some(spanned) = self.push(byte) {
+                emit(spanned);
+            }
+        }
+    }
+}
+
+/// Passes every token of `set` in `reader` to `emit`, reading `chunk_size` bytes at a
+/// time so memory use does not grow with the input.
+pub fn scan(
+    mut reader: impl Read,
+    chunk_size: usize,
+    set: &InstructionSet,
+    mut emit: impl FnMut(Spanned),
+) -> io::Result<()> {
+    let mut scanner = Scanner::new(set);
+    let mut buffer = vec![0; chunk_size.max(1)];
+    loop {
+        let len = match reader.read(&mut buffer) {
+            Ok(0) => return Ok(()),
+            Ok(len) => len,
+            Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        };
+        scanner.feed(&buffer[..len], &mut emit);
+    }
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+#[test]
+fn test_every_chunk_size() {
+    let set = InstructionSet::part2();
+    let expected: Vec<Spanned> = crate::lexer::Lexer::new(EXAMPLE, &set).collect();
+    assert_eq!(expected.len(), 6);
+    for chunk_size in 1..=EXAMPLE.len() {
+        let mut tokens = Vec::new();
+        scan(EXAMPLE.as_bytes(), chunk_size, &set, |spanned| {
+            tokens.push(spanned)
+        })
+        .unwrap();
+        assert_eq!(tokens, expected, "chunk size {chunk_size}");
+    }
+}
+
+#[test]
+fn test_spans_across_chunks() {
+    let mut scanner = Scanner::new(&InstructionSet::part2());
+    let mut tokens = Vec::new();
+    for chunk in ["xxmu", "l(12", "3,4", ")d", "on'", "t()do(", ")"] {
+        scanner.feed(chunk.as_bytes(), |spanned| tokens.push(spanned));
+    }
+
+    assert_eq!(
+        tokens,
+        [
+            Spanned {
+                token: Token::new("mul", &[123, 4]),
+                span: 2..12,
+            },
+            Spanned {
+                token: Token::new("don't", &[]),
+                span: 12..19,
+            },
+            Spanned {
+                token: Token::new("do", &[]),
+                span: 19..23,
+            },
+        ]
+    );
+}
+
+/// Tries each instruction in turn at each offset, skipping past a match.
+#[cfg(test)]
+fn reference_tokens(set: &InstructionSet, input: &str) -> Vec<Spanned> {
+    fn operand(bytes: &[u8], pos: &mut usize) -> Option<u32> {
+        let digits = bytes[*pos..]
+            .iter()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count();
+        if !(1..=3).contains(&digits) {
+            return None;
+        }
+        let value = bytes[*pos..*pos + digits]
+            .iter()
+            .fold(0, |value, &digit| value * 10 + u32::from(digit - b'0'));
+        *pos += digits;
+        Some(value)
+    }
+    fn expect(bytes: &[u8], pos: &mut usize, byte: u8) -> Option<()> {
+        (bytes.get(*pos) == Some(&byte)).then(|| *pos += 1)
+    }
+
+    let bytes = input.as_bytes();
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    while start < bytes.len() {
+        let matched = set.iter().find_map(|instruction| {
+            let mut pos = start;
+            for &byte in instruction.name().as_bytes() {
+                expect(bytes, &mut pos, byte)?;
+            }
+            expect(bytes, &mut pos, b'(')?;
+            let mut operands = Vec::new();
+            for idx in 0..instruction.arity() {
+                if idx > 0 {
+                    expect(bytes, &mut pos, b',')?;
+                }
+                operands.push(operand(bytes, &mut pos)?);
+            }
+            expect(bytes, &mut pos, b')')?;
+            Some(Spanned {
+                token: Token {
+                    name: instruction.name(),
+                    operands,
+                },
+                span: start..pos,
+            })
+        });
+        match matched {
+            Some(spanned) => {
+                start = spanned.span.end;
+                tokens.push(spanned);
+            }
+            None => start += 1,
+        }
+    }
+    tokens
+}
+
+#[test]
+fn test_matches_reference_with_overlapping_names() {
+    use crate::interpreter::{Instruction, Machine, Overflow};
+
+    struct Nop(&'static str, usize);
+    impl Instruction for Nop {
+        fn name(&self) -> &'static str {
+            self.0
+        }
+
+        fn arity(&self) -> usize {
+            self.1
+        }
+
+        fn execute(&self, _: &mut Machine, _: &[u32]) -> Result<(), Overflow> {
+            Ok(())
+        }
+    }
+
+    // Names that are prefixes and suffixes of each other, and one that
+    // shares a name with the start of `don't`.
+    let set = InstructionSet::extended()
+        .with(Nop("a", 0))
+        .with(Nop("ad", 1))
+        .with(Nop("aad", 3))
+        .with(Nop("d", 2));
+    let pieces = [
+        "mul(", "do", "don't", "()", "(", ")", ",", "1", "23", "4567", "a", "ad", "add(", "aad(",
+        "d(", "sub(", "reset", "x",
+    ];
+    let count = u32::try_from(pieces.len()).unwrap();
+    let mut rng = aoc_common::random::Lcg::new(0x2024);
+    for _ in 0..300 {
+        let input: String = (0..40)
+            .map(|_| pieces[usize::try_from(rng.below(count)).unwrap()])
+            .collect();
+        let expected = reference_tokens(&set, &input);
+        for chunk_size in [1, 3, 64] {
+            let mut tokens = Vec::new();
+            scan(input.as_bytes(), chunk_size, &set, |spanned| {
+                tokens.push(spanned)
+            })
+            .unwrap();
+            assert_eq!(tokens, expected, "{input:?} in chunks of {chunk_size}");
+        }
+    }
+}
@@ -0,0 +1,134 @@
+//! An execution trace of an instruction set, for `day03 --trace`.
+
+use std::fmt;
+
+use crate::{
+    interpreter::{InstructionSet, Machine, OverflowError},
+    lexer::Spanned,
+};
+
+/// One instruction as the set runs it.
+pub struct Step {
+    pub spanned: Spanned,
+    /// Whether the machine was enabled when the instruction was reached.
+    pub enabled: bool,
+    /// What an instruction with operands adds when enabled, as `a * b` for
+    /// a `mul`, whether or not it counted.
+    pub value: Option<i128>,
+    /// The running total after the instruction.
+    pub total: i128,
+}
+
+impl Step {
+    /// An instruction with a value that did not count because the machine
+    /// was disabled.
+    pub fn skipped(&self) -> bool {
+        self.value.is_some() && !self.enabled
+    }
+}
+
+pub fn trace(set: &InstructionSet, tokens: &[Spanned]) -> Result<Vec<Step>, OverflowError> {
+    let mut machine = Machine::default();
+    let mut steps = Vec::with_capacity(tokens.len());
+    for spanned in tokens {
+        let enabled = machine.enabled;
+        set.execute_token(&mut machine, spanned)?;
+        steps.push(Step {
+            spanned: spanned.clone(),
+            enabled,
+            value: value(set, spanned),
+            total: machine.accumulator,
+        });
+    }
+    Ok(steps)
+}
+
+/// Runs the instruction on a fresh, enabled machine to see what it adds.
+fn value(set: &InstructionSet, spanned: &Spanned) -> Option<i128> {
+    let token = &spanned.token;
+    let instruction = set
+        .get(token.name)
+        .filter(|instruction| instruction.arity() > 0)?;
+    let mut probe = Machine::default();
+    instruction.execute(&mut probe, &token.operands).ok()?;
+    Some(probe.accumulator)
+}
+
+pub fn table_header() -> String {
+    format!(
+        "{:>8}  {:<13}  {:<8}  {:>7}  {:>10}",
+        "offset", "instruction", "state", "value", "total"
+    )
+}
+
+/// One row of the table under [`table_header`].
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = if self.enabled { "enabled" } else { "disabled" };
+        let value = self
+            .value
+            .map_or_else(String::new, |value| value.to_string());
+        write!(
+            f,
+            "{:>8}  {:<13}  {state:<8}  {value:>7}  {:>10}",
+            self.spanned.span.start,
+            self.spanned.token.to_string(),
+            self.total
+        )
+    }
+}
+
+#[test]
+fn test_trace_example() {
+    use crate::lexer::Lexer;
+
+    let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+    let set = InstructionSet::part2();
+    let tokens: Vec<Spanned> = Lexer::new(input, &set).collect();
+    let steps = trace(&set, &tokens).unwrap();
+    let rows: Vec<String> = steps.iter().map(Step::to_string).collect();
+
+    assert_eq!(
+        rows,
+        [
+            "       1  mul(2,4)       enabled         8           8",
+            "      20  don't()        enabled                     8",
+            "      28  mul(5,5)       disabled       25           8",
+            "      48  mul(11,8)      disabled       88           8",
+            "      59  do()           disabled                    8",
+            "      64  mul(8,5)       enabled        40          48",
+        ]
+    );
+    let skipped: Vec<usize> = steps
+        .iter()
+        .filter(|step| step.skipped())
+        .map(|step| step.spanned.span.start)
+        .collect();
+    assert_eq!(skipped, [28, 48]);
+}
+
+#[test]
+fn test_trace_extended() {
+    use crate::lexer::Lexer;
+
+    let input = "sub(1,10)don't()add(2,2)reset()do()reset()";
+    let set = InstructionSet::extended();
+    let tokens: Vec<Spanned> = Lexer::new(input, &set).collect();
+    let rows: Vec<String> = trace(&set, &tokens)
+        .unwrap()
+        .iter()
+        .map(Step::to_string)
+        .collect();
+
+    assert_eq!(
+        rows,
+        [
+            "       0  sub(1,10)      enabled        -9          -9",
+            "       9  don't()        enabled                    -9",
+            "      16  add(2,2)       disabled        4          -9",
+            "      24  reset()        disabled                   -9",
+            "      31  do()           disabled                   -9",
+            "      35  reset()        enabled                     0",
+        ]
+    );
+}
@@ -0,0 +1,265 @@
+//! Searching for many words at once, for `day04 --dictionary`.
+//!
+//! The words are compiled into an Aho-Corasick automaton: a trie of their
+//! letters where every node also links to the longest proper suffix of it
+//! that is still in the trie. Each line of the grid in each of the eight
+//! directions is then fed through the automaton once, finding every word
+//! that ends at each cell, so the cost of a search does not grow with the
+//! number of words.
+
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt, fs,
+    path::Path,
+};
+
+use aoc_common::{Direction, Grid};
+
+use crate::WordMatch;
+
+const ROOT: usize = 0;
+
+#[derive(Debug, Default)]
+struct Node {
+    next: HashMap<char, usize>,
+    /// The node for the longest proper suffix of this one's letters.
+    fail: usize,
+    /// Words ending here, including those reached through `fail`.
+    outputs: Vec<usize>,
+}
+
+pub struct Dictionary {
+    words: Vec<String>,
+    /// Length of each word in letters.
+    lengths: Vec<usize>,
+    nodes: Vec<Node>,
+}
+
+/// An occurrence of the word at index `word` of the [`Dictionary`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Found {
+    pub word: usize,
+    pub at: WordMatch,
+}
+
+impl Dictionary {
+    /// Builds the automaton for `words`, ignoring empty and repeated ones.
+    pub fn new<S: AsRef<str>>(words: impl IntoIterator<Item = S>) -> Self {
+        let mut seen = HashSet::new();
+        let mut dictionary = Self {
+            words: Vec::new(),
+            lengths: Vec::new(),
+            nodes: vec![Node::default()],
+        };
+        for word in words {
+            let word = word.as_ref();
+            if !word.is_empty() && seen.insert(word.to_string()) {
+                dictionary.insert(word);
+            }
+        }
+        dictionary.link();
+        dictionary
+    }
+
+    /// One word per line. Blank lines and lines starting with `#` are
+    /// skipped, and surrounding whitespace is trimmed.
+    pub fn parse(text: &str) -> Self {
+        Self::new(
+            text.lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#')),
+        )
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|err| format!("unable to read {}: {err}", path.display()))?;
+        Ok(Self::parse(&text))
+    }
+
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+
+    fn insert(&mut self, word: &str) {
+        let mut node = ROOT;
+        for letter in word.chars() {
+            node = match self.nodes[node].next.get(&letter) {
+                Some(&next) => next,
+                None => {
+                    self.nodes.push(Node::default());
+                    let next = self.nodes.len() - 1;
+                    self.nodes[node].next.insert(letter, next);
+                    next
+                }
+            };
+        }
+        self.nodes[node].outputs.push(self.words.len());
+        self.words.push(word.to_string());
+        self.lengths.push(word.chars().count());
+    }
+
+    /// Sets the `fail` links breadth first, so a node's link is always set
+    /// before those of its children, which are found from it.
+    fn link(&mut self) {
+        let mut queue: VecDeque<usize> = self.nodes[ROOT].next.values().copied().collect();
+        while let Some(node) = queue.pop_front() {
+            let children: Vec<(char, usize)> = self.nodes[node]
+                .next
+                .iter()
+                .map(|(&letter, &child)| (letter, child))
+                .collect();
+            for (letter, child) in children {
+                let fail = self.step(self.nodes[node].fail, letter);
+                let inherited = self.nodes[fail].outputs.clone();
+                self.nodes[child].fail = fail;
+                self.nodes[child].outputs.extend(inherited);
+                queue.push_back(child);
+            }
+        }
+    }
+
+    fn step(&self, mut node: usize, letter: char) -> usize {
+        loop {
+            if let Some(&next) = self.nodes[node].next.get(&letter) {
+                return next;
+            }
+            if node == ROOT {
+                return ROOT;
+            }
+            node = self.nodes[node].fail;
+        }
+    }
+
+    /// Every occurrence of every word, with the same matches
+    /// [`find_word`](crate::find_word) gives for each word on its own. They
+    /// are ordered by word, then in the order `find_word` gives them.
+    pub fn search(&self, input_grid: &Grid<char>) -> Vec<Found> {
+        let mut found = Vec::new();
+        let mut line = Vec::new();
+        for direction in Direction::ALL {
+            let step = direction.vector();
+            // Each line starts at a cell with no cell behind it.
+            let starts = input_grid
+                .points()
+                .filter(|&point| input_grid.offset(point, -step).is_none());
+            for start in starts {
+                line.clear();
+                line.extend(input_grid.ray(start, step));
+
+                let mut node = ROOT;
+                for (end, &point) in line.iter().enumerate() {
+                    node = self.step(node, input_grid[point]);
+                    for &word in &self.nodes[node].outputs {
+                        let len = self.lengths[word];
+                        // A one-letter word would otherwise be found once per
+                        // direction.
+                        if len == 1 && direction != Direction::East {
+                            continue;
+                        }
+                        found.push(Found {
+                            word,
+                            at: WordMatch {
+                                start: line[end + 1 - len],
+                                direction,
+                            },
+                        });
+                    }
+                }
+            }
+        }
+
+        found.sort_by_key(|found| {
+            let direction = Direction::ALL
+                .iter()
+                .position(|&direction| direction == found.at.direction);
+            (found.word, found.at.start.y, found.at.start.x, direction)
+        });
+        found
+    }
+}
+
+/// The result of [`Dictionary::search`], for printing.
+pub struct Search<'a> {
+    dictionary: &'a Dictionary,
+    pub found: Vec<Found>,
+}
+
+impl<'a> Search<'a> {
+    pub fn new(dictionary: &'a Dictionary, input_grid: &Grid<char>) -> Self {
+        Self {
+            dictionary,
+            found: dictionary.search(input_grid),
+        }
+    }
+
+    pub fn count(&self, word: usize) -> usize {
+        self.found.iter().filter(|found| found.word == word).count()
+    }
+}
+
+impl fmt::Display for Search<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let words = self.dictionary.words();
+        let width = words.iter().map(|word| word.chars().count()).max();
+        let width = width.unwrap_or_default();
+        for found in &self.found {
+            let start = found.at.start;
+            writeln!(
+                f,
+                "{:<width$}  ({}, {})  {:?}",
+                words[found.word], start.x, start.y, found.at.direction
+            )?;
+        }
+
+        writeln!(f, "Matches: {}", self.found.len())?;
+        for (index, word) in words.iter().enumerate() {
+            writeln!(f, "  {word}: {}", self.count(index))?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_search_matches_find_word() {
+    use aoc_common::Solution;
+
+    let input = "MMMSXXMASM\nMSAMXMSMSA\nAMXSXMAAMM\nMSAMASMSMX\nXMASAMXAMM\n\
+                 XXAMMXXAMA\nSMSMSASXSS\nSAXAMASAAA\nMAMMMXMMMM\nMXMXAXMASX\n";
+    let input_grid = crate::Day04::parse(input).unwrap();
+    // Overlapping words, a prefix of another word, a word that is its own
+    // reverse, one that never occurs and a single letter.
+    let dictionary = Dictionary::parse("XMAS\n# comment\nMAS\nXMASX\nAMA\n\nXMAS\nQ\nA\n");
+    assert_eq!(
+        dictionary.words(),
+        ["XMAS", "MAS", "XMASX", "AMA", "Q", "A"]
+    );
+
+    let found = dictionary.search(&input_grid);
+    for (index, word) in dictionary.words().iter().enumerate() {
+        let matches: Vec<WordMatch> = found
+            .iter()
+            .filter(|found| found.word == index)
+            .map(|found| found.at)
+            .collect();
+        assert_eq!(matches, crate::find_word(&input_grid, word), "{word}");
+    }
+    assert_eq!(Search::new(&dictionary, &input_grid).count(0), 18);
+}
+
+#[test]
+fn test_search_display() {
+    let input_grid = Grid::from_rows(vec!["SAMXMAS".chars().collect()]);
+    let dictionary = Dictionary::new(["XMAS", "MAS", "SAX"]);
+    assert_eq!(
+        Search::new(&dictionary, &input_grid).to_string(),
+        "XMAS  (3, 0)  East\n\
+         XMAS  (3, 0)  West\n\
+         MAS   (2, 0)  West\n\
+         MAS   (4, 0)  East\n\
+         Matches: 4\n  \
+         XMAS: 2\n  \
+         MAS: 2\n  \
+         SAX: 0\n"
+    );
+}
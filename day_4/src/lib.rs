@@ -0,0 +1,222 @@
+pub mod dictionary;
+
+use aoc_common::{Answer, Direction, Grid, ParseError, Point, Solution, SolveError, Vector};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Parsed = Grid<char>;
+
+    fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        Grid::parse_chars(Self::DAY, input)
+    }
+
+    fn part1(input_grid: &Grid<char>) -> Result<Answer, SolveError> {
+        Ok(count_xmas(input_grid).into())
+    }
+
+    fn part2(input_grid: &Grid<char>) -> Result<Answer, SolveError> {
+        Ok(count_x_mas(input_grid).into())
+    }
+}
+
+/// Reads up to `len` characters from `start` in `direction`, stopping at the
+/// edge of the grid.
+fn read_word(input_grid: &Grid<char>, start: Point, direction: Direction, len: usize) -> String {
+    input_grid
+        .ray(start, direction.vector())
+        .take(len)
+        .map(|point| input_grid[point])
+        .collect()
+}
+
+fn matches_either_way(word: &str, target: &str) -> bool {
+    word == target || word.chars().rev().eq(target.chars())
+}
+
+/// Where a word was found: its first letter and the way it reads from there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WordMatch {
+    pub start: Point,
+    pub direction: Direction,
+}
+
+/// Every occurrence of `word` reading in any of the eight directions, in
+/// row-major order of the start cell. A one-letter word reads the same in
+/// every direction, so it is reported once per cell, facing east.
+pub fn find_word(input_grid: &Grid<char>, word: &str) -> Vec<WordMatch> {
+    let letters: Vec<char> = word.chars().collect();
+    let letters = &letters;
+    let Some(&first) = letters.first() else {
+        return Vec::new();
+    };
+    let directions: &[Direction] = if letters.len() == 1 {
+        &[Direction::East]
+    } else {
+        &Direction::ALL
+    };
+
+    input_grid
+        .iter()
+        .filter(|&(_, &letter)| letter == first)
+        .flat_map(|(start, _)| {
+            directions
+                .iter()
+                .filter(move |direction| {
+                    input_grid
+                        .ray(start, direction.vector())
+                        .take(letters.len())
+                        .map(|point| input_grid[point])
+                        .eq(letters.iter().copied())
+                })
+                .map(move |&direction| WordMatch { start, direction })
+        })
+        .collect()
+}
+
+fn count_xmas(input_grid: &Grid<char>) -> usize {
+    find_word(input_grid, "XMAS").len()
+}
+
+fn count_x_mas(input_grid: &Grid<char>) -> usize {
+    input_grid
+        .points()
+        .filter(|&start| {
+            let forward_slash = read_word(input_grid, start, Direction::SouthEast, 3);
+            let backward_slash = input_grid
+                .offset(start, Vector::new(2, 0))
+                .map(|corner| read_word(input_grid, corner, Direction::SouthWest, 3))
+                .unwrap_or_default();
+            matches_either_way(&forward_slash, "MAS") && matches_either_way(&backward_slash, "MAS")
+        })
+        .count()
+}
+
+#[test]
+fn horizontal() {
+    let input_grid = Grid::from_rows(vec![vec!['X', 'M', 'A', 'S']]);
+    let result = count_xmas(&input_grid);
+    assert_eq!(result, 1);
+}
+
+#[test]
+fn horizontal_reverse() {
+    let input_grid = Grid::from_rows(vec![vec!['S', 'A', 'M', 'X']]);
+    let result = count_xmas(&input_grid);
+    assert_eq!(result, 1);
+}
+
+#[test]
+fn vertical() {
+    let input_grid = Grid::from_rows(vec![vec!['X'], vec!['M'], vec!['A'], vec!['S']]);
+    let result = count_xmas(&input_grid);
+    assert_eq!(result, 1);
+}
+
+#[test]
+fn vertical_reverse() {
+    let input_grid = Grid::from_rows(vec![vec!['S'], vec!['A'], vec!['M'], vec!['X']]);
+    let result = count_xmas(&input_grid);
+    assert_eq!(result, 1);
+}
+
+#[test]
+fn diagonal_bottom_right() {
+    let input_grid = Grid::from_rows(vec![
+        vec!['X', 'B', 'B', 'B'],
+        vec!['B', 'M', 'B', 'B'],
+        vec!['B', 'B', 'A', 'B'],
+        vec!['B', 'B', 'B', 'S'],
+    ]);
+    let result = count_xmas(&input_grid);
+    assert_eq!(result, 1);
+}
+
+#[test]
+fn diagonal_bottom_right_reverse() {
+    let input_grid = Grid::from_rows(vec![
+        vec!['S', 'B', 'B', 'B'],
+        vec!['B', 'A', 'B', 'B'],
+        vec!['B', 'B', 'M', 'B'],
+        vec!['B', 'B', 'B', 'X'],
+    ]);
+    let result = count_xmas(&input_grid);
+    assert_eq!(result, 1);
+}
+
+#[test]
+fn diagonal_bottom_left() {
+    let input_grid = Grid::from_rows(vec![
+        vec!['B', 'B', 'B', 'X'],
+        vec!['B', 'B', 'M', 'B'],
+        vec!['B', 'A', 'B', 'B'],
+        vec!['S', 'B', 'B', 'B'],
+    ]);
+    let result = count_xmas(&input_grid);
+    assert_eq!(result, 1);
+}
+
+#[test]
+fn diagonal_bottom_left_reverse() {
+    let input_grid = Grid::from_rows(vec![
+        vec!['B', 'B', 'B', 'S'],
+        vec!['B', 'B', 'A', 'B'],
+        vec!['B', 'M', 'B', 'B'],
+        vec!['X', 'B', 'B', 'B'],
+    ]);
+    let result = count_xmas(&input_grid);
+    assert_eq!(result, 1);
+}
+
+#[test]
+fn find_word_directions() {
+    let input_grid = Grid::from_rows(vec![
+        vec!['S', 'A', 'M', 'X'],
+        vec!['B', 'B', 'M', 'B'],
+        vec!['B', 'A', 'B', 'A'],
+        vec!['S', 'B', 'B', 'S'],
+    ]);
+    let result = find_word(&input_grid, "XMAS");
+    assert_eq!(
+        result,
+        vec![
+            WordMatch {
+                start: Point::new(3, 0),
+                direction: Direction::SouthWest,
+            },
+            WordMatch {
+                start: Point::new(3, 0),
+                direction: Direction::West,
+            },
+        ]
+    );
+
+    assert_eq!(find_word(&input_grid, "B").len(), 7);
+    assert_eq!(find_word(&input_grid, "").len(), 0);
+}
+
+#[test]
+fn x_mas_forwards() {
+    let input_grid = Grid::from_rows(vec![
+        vec!['B', 'M', 'B', 'M'],
+        vec!['B', 'B', 'A', 'B'],
+        vec!['B', 'S', 'B', 'S'],
+        vec!['B', 'B', 'B', 'B'],
+    ]);
+    let result = count_x_mas(&input_grid);
+    assert_eq!(result, 1);
+}
+
+#[test]
+fn x_mas_reverse() {
+    let input_grid = Grid::from_rows(vec![
+        vec!['B', 'S', 'B', 'S'],
+        vec!['B', 'B', 'A', 'B'],
+        vec!['B', 'M', 'B', 'M'],
+        vec!['B', 'B', 'B', 'B'],
+    ]);
+    let result = count_x_mas(&input_grid);
+    assert_eq!(result, 1);
+}
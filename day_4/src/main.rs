@@ -0,0 +1,59 @@
+use std::{path::PathBuf, process::ExitCode};
+
+use aoc_common::{
+    input::Source,
+    runner::{self, Args, Modes},
+    Solution,
+};
+use day04::{
+    dictionary::{Dictionary, Search},
+    Day04,
+};
+
+const USAGE: &str = "\
+Usage:
+    day04 [--input <PATH> | --example]
+    day04 --dictionary <PATH> [--input <PATH> | --example]
+
+--dictionary searches the grid for every word listed in <PATH>, one per
+line, in a single pass. Blank lines and lines starting with `#` are
+skipped. Each match is listed with its word, the position of its first
+letter and the direction it reads in, then the matches are counted by
+word.";
+
+enum Mode {
+    Dictionary(PathBuf),
+}
+
+impl Modes for Mode {
+    fn usage(_: u8) -> String {
+        USAGE.to_string()
+    }
+
+    fn parse(args: &mut Args<'_>) -> Result<Option<Self>, String> {
+        let mut dictionary = None;
+        while let Some(arg) = args.next_arg()? {
+            match arg {
+                "--dictionary" => dictionary = Some(PathBuf::from(args.value(arg)?)),
+                other => return Err(format!("Unknown argument `{other}`")),
+            }
+        }
+        Ok(dictionary.map(Mode::Dictionary))
+    }
+
+    fn run(self, source: &Source) -> Result<(), String> {
+        match self {
+            Mode::Dictionary(path) => {
+                let dictionary = Dictionary::load(&path)?;
+                let input = source.read().map_err(|err| err.to_string())?;
+                let input_grid = Day04::parse(&input).map_err(|err| err.to_string())?;
+                print!("{}", Search::new(&dictionary, &input_grid));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn main() -> ExitCode {
+    runner::main_with::<Day04, Mode>()
+}
@@ -0,0 +1,256 @@
+use std::collections::{HashMap, HashSet};
+use std::iter;
+use std::ops::Div;
+
+use aoc_common::{
+    parse::{lines, Line},
+    Answer, ParseError, Solution, SolveError,
+};
+
+pub struct Day05;
+
+pub struct Manual {
+    rules: HashMap<u32, HashSet<u32>>,
+    updates: Vec<Vec<u32>>,
+}
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Parsed = Manual;
+
+    fn parse(input: &str) -> Result<Manual, ParseError> {
+        let lines: Vec<Line> = lines(Self::DAY, input).collect();
+        let Some(blank) = lines.iter().position(|line| line.text.is_empty()) else {
+            return Err(ParseError::whole_input(
+                Self::DAY,
+                "expected a blank line between the rules and the updates",
+            ));
+        };
+        let (rules, updates) = (&lines[..blank], &lines[blank + 1..]);
+        let end = updates
+            .iter()
+            .rposition(|line| !line.text.is_empty())
+            .map_or(0, |last| last + 1);
+        Ok(Manual {
+            rules: build_rules(rules.iter().copied())?,
+            updates: updates[..end]
+                .iter()
+                .map(parse_update)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn part1(manual: &Manual) -> Result<Answer, SolveError> {
+        Ok(manual
+            .updates
+            .iter()
+            .filter(|update| is_ordered(update, &manual.rules))
+            .map(|update| *update.get(update.len().div(2)).unwrap())
+            .sum::<u32>()
+            .into())
+    }
+
+    fn part2(manual: &Manual) -> Result<Answer, SolveError> {
+        Ok(manual
+            .updates
+            .iter()
+            .filter(|update| !is_ordered(update, &manual.rules))
+            .map(|invalid_update| {
+                let sorted_update = sort_updates(invalid_update.clone(), &manual.rules);
+                *sorted_update.get(sorted_update.len().div(2)).unwrap()
+            })
+            .sum::<u32>()
+            .into())
+    }
+}
+
+struct RuleItem {
+    afters: HashSet<u32>,
+    before: u32,
+}
+
+impl PartialEq for RuleItem {
+    fn eq(&self, other: &Self) -> bool {
+        (self.before == other.before) && (self.afters == other.afters)
+    }
+}
+
+impl Eq for RuleItem {}
+
+impl Ord for RuleItem {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (
+            other.afters.contains(&self.before),
+            self.afters.contains(&other.before),
+        ) {
+            (true, false) => std::cmp::Ordering::Greater,
+            (false, true) => std::cmp::Ordering::Less,
+            (true, true) => panic!("Can never satisfy rule"),
+            (false, false) => {
+                if self.afters.len() > other.afters.len() {
+                    std::cmp::Ordering::Less
+                } else if self.afters.len() < other.afters.len() {
+                    std::cmp::Ordering::Greater
+                } else {
+                    std::cmp::Ordering::Equal
+                }
+            }
+        }
+    }
+}
+
+impl PartialOrd for RuleItem {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl RuleItem {
+    fn new(before: u32, afters: HashSet<u32>) -> Self {
+        Self { afters, before }
+    }
+}
+
+fn sort_updates(update: Vec<u32>, rules: &HashMap<u32, HashSet<u32>>) -> Vec<u32> {
+    let mut relevant_rules = rules.clone();
+    relevant_rules.retain(|k, _| update.contains(k));
+    for nums in relevant_rules.values_mut() {
+        nums.retain(|num| update.contains(num));
+    }
+    let mut relevant_rule_items: Vec<RuleItem> = relevant_rules
+        .into_iter()
+        .map(|(before, afters)| RuleItem::new(before, afters))
+        .collect();
+    relevant_rule_items.sort_unstable();
+    let mut sorted_update: Vec<u32> = relevant_rule_items
+        .into_iter()
+        .map(|item| item.before)
+        .collect();
+    for num in update {
+        if !sorted_update.contains(&num) {
+            sorted_update.push(num);
+        }
+    }
+    sorted_update
+}
+
+fn parse_update(update: &Line) -> Result<Vec<u32>, ParseError> {
+    update
+        .text
+        .split(',')
+        .map(|num| update.number::<u32>(num))
+        .collect()
+}
+
+fn is_ordered(update: &[u32], rules: &HashMap<u32, HashSet<u32>>) -> bool {
+    let mut invalid_nums: HashSet<u32> = HashSet::new();
+    let mut valid = true;
+
+    update.iter().rev().for_each(|num| {
+        if invalid_nums.contains(num) {
+            valid = false;
+        } else if let Some(new_invalid_nums) = rules.get(num) {
+            invalid_nums.extend(new_invalid_nums);
+        }
+    });
+
+    valid
+}
+
+fn build_rules<'a>(
+    rule_lines: impl IntoIterator<Item = Line<'a>>,
+) -> Result<HashMap<u32, HashSet<u32>>, ParseError> {
+    let mut rules = HashMap::new();
+    for line in rule_lines {
+        let (left, right) = if let Some((left, right)) = line.text.split_once('|') {
+            (line.number::<u32>(left)?, line.number::<u32>(right)?)
+        } else {
+            return Err(line.error(line.text, "invalid rule, expected `X|Y`"));
+        };
+        rules
+            .entry(left)
+            .and_modify(|afters: &mut HashSet<u32>| {
+                afters.insert(right);
+            })
+            .or_insert(iter::once(right).collect());
+    }
+    Ok(rules)
+}
+
+#[test]
+fn test_build_rules() {
+    let rules_input = "47|53\n97|13";
+    let rules = build_rules(lines(Day05::DAY, rules_input)).unwrap();
+    let mut expected: HashMap<u32, HashSet<u32>> = HashMap::new();
+    expected.insert(47, iter::once(53).collect());
+    expected.insert(97, iter::once(13).collect());
+    assert_eq!(rules, expected);
+}
+
+#[test]
+fn test_build_rules_with_multiple_afters() {
+    let rules_input = "47|53\n47|13";
+    let rules: HashMap<u32, HashSet<u32>> = build_rules(lines(Day05::DAY, rules_input)).unwrap();
+    let mut expected = HashMap::new();
+    expected.insert(47, [53, 13].into_iter().collect());
+    assert_eq!(rules, expected);
+}
+
+#[test]
+fn test_simple_update() {
+    let update = [75, 29, 13];
+    let rules = HashMap::new();
+
+    let result = is_ordered(&update, &rules);
+    assert!(result);
+}
+
+#[test]
+fn test_invalid_update() {
+    let update = [75, 29, 13];
+    let mut rules = HashMap::new();
+    rules.insert(13, iter::once(29).collect());
+
+    let result = is_ordered(&update, &rules);
+    assert!(!result);
+}
+
+#[test]
+fn test_sort_invalid_update() {
+    let invalid_update = vec![75, 29, 13];
+    let mut rules = HashMap::new();
+    rules.insert(13, iter::once(29).collect());
+    rules.insert(75, iter::once(13).collect());
+
+    let result = sort_updates(invalid_update, &rules);
+    assert_eq!(result, vec![75, 13, 29]);
+}
+
+#[test]
+fn test_build_invalid_rule() {
+    let err = build_rules(lines(Day05::DAY, "47|53\n97-13")).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "day 5, line 2, column 1: invalid rule, expected `X|Y` `97-13`"
+    );
+}
+
+#[test]
+fn test_parse_invalid_update() {
+    let err = Day05::parse("47|53\n\n75,47\n97,,13").err().unwrap();
+    assert_eq!(err.to_string(), "day 5, line 4, column 4: missing number");
+}
+
+#[test]
+fn test_parse_line_endings() {
+    for input in [
+        "47|53\n\n75,47,53\n\n",
+        "47|53\r\n\r\n75,47,53\r\n",
+        "47|53\n\n75,47,53",
+    ] {
+        let manual = Day05::parse(input).unwrap();
+        assert_eq!(manual.updates, vec![vec![75, 47, 53]], "{input:?}");
+        assert_eq!(manual.rules[&47], HashSet::from([53]), "{input:?}");
+    }
+}
@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day05::Day05;
+
+fn main() -> ExitCode {
+    aoc_common::runner::main::<Day05>()
+}
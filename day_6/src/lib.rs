@@ -0,0 +1,268 @@
+use std::collections::HashSet;
+
+use aoc_common::{Answer, Direction, Grid, ParseError, Point, Solution, SolveError};
+
+#[derive(Debug, Clone)]
+struct Guard {
+    direction: Direction,
+    position: Point,
+    directions_to_confirm_loop: HashSet<Direction>,
+    path_patrolled: bool,
+}
+
+impl Guard {
+    fn new(direction: char, position: Point) -> Option<Self> {
+        let direction = Direction::try_from(direction).ok()?;
+        Some(Self {
+            direction,
+            position,
+            directions_to_confirm_loop: Direction::CARDINAL.into_iter().collect(),
+            path_patrolled: false,
+        })
+    }
+
+    fn next_position(&self) -> Option<Point> {
+        self.position.checked_add(self.direction.vector())
+    }
+
+    fn turn(&mut self) {
+        self.direction = self.direction.turn_right();
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    map: Grid<char>,
+    guard: Guard,
+}
+
+impl Map {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let mut guard = None;
+        let map = Grid::parse(Day06::DAY, input, |line, x, c| {
+            if c != '.' && c != '#' {
+                match Guard::new(c, Point::new(x, line.number - 1)) {
+                    Some(_) if guard.is_some() => {
+                        return Err(line.error_at(x, &c.to_string(), "second guard in map"))
+                    }
+                    Some(new_guard) => guard = Some(new_guard),
+                    None => return Err(line.error_at(x, &c.to_string(), "unknown map character")),
+                }
+            }
+            Ok(c)
+        })?;
+        let guard =
+            guard.ok_or_else(|| ParseError::whole_input(Day06::DAY, "no guard found in map"))?;
+        Ok(Self { map, guard })
+    }
+
+    fn out_of_map(&self) -> bool {
+        !self.map.contains(self.guard.position)
+    }
+
+    fn walk(&mut self) -> bool {
+        if self.out_of_map() {
+            return false;
+        }
+
+        let Some(next_position) = self.guard.next_position() else {
+            self.map[self.guard.position] = 'X';
+            return false;
+        };
+
+        match self.map.get(next_position) {
+            Some('#') => {
+                if self.guard.path_patrolled {
+                    self.guard
+                        .directions_to_confirm_loop
+                        .remove(&self.guard.direction);
+                } else {
+                    self.guard.directions_to_confirm_loop =
+                        Direction::CARDINAL.into_iter().collect();
+                };
+                self.guard.turn();
+            }
+            Some(&c) => {
+                self.guard.path_patrolled = match c {
+                    '.' => false,
+                    'X' => true,
+                    _ => panic!("Unknown char in map"),
+                };
+                self.map[self.guard.position] = 'X';
+                self.guard.position = next_position;
+            }
+            None => {
+                self.map[self.guard.position] = 'X';
+                self.guard.position = next_position;
+            }
+        }
+        true
+    }
+
+    fn count_patrol_spots(&self) -> usize {
+        self.map.iter().filter(|(_, &c)| c == 'X').count()
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Parsed = Map;
+
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        Map::new(input)
+    }
+
+    fn part1(map: &Map) -> Result<Answer, SolveError> {
+        let mut map = map.clone();
+        while map.walk() {}
+        Ok(map.count_patrol_spots().into())
+    }
+
+    fn part2(map: &Map) -> Result<Answer, SolveError> {
+        Ok(map
+            .map
+            .iter()
+            .filter(|(_, &c)| c == '.')
+            .map(|(point, _)| {
+                let mut map = map.clone();
+                map.map[point] = '#';
+
+                let mut is_loop = false;
+                while map.walk() {
+                    if map.guard.directions_to_confirm_loop.is_empty() {
+                        is_loop = true;
+                        break;
+                    }
+                }
+
+                u32::from(is_loop)
+            })
+            .sum::<u32>()
+            .into())
+    }
+}
+
+#[test]
+fn test_map_creation() {
+    let input = "..<";
+    let map = Map::new(input).unwrap();
+    let expected_map = Grid::from_rows(vec![vec!['.', '.', '<']]);
+
+    assert_eq!(map.map, expected_map);
+    assert_eq!(map.guard.position, Point::new(2, 0));
+    assert_eq!(map.guard.direction, Direction::West);
+}
+
+#[test]
+fn test_walk() {
+    let mut map = Map {
+        map: Grid::from_rows(vec![vec!['.', '.', '<']]),
+        guard: Guard {
+            direction: Direction::West,
+            position: Point::new(2, 0),
+            directions_to_confirm_loop: Direction::CARDINAL.into_iter().collect(),
+            path_patrolled: false,
+        },
+    };
+
+    let can_walk = map.walk();
+    assert!(can_walk);
+    assert_eq!(map.guard.direction, Direction::West);
+    assert_eq!(map.guard.position, Point::new(1, 0));
+    assert_eq!(map.map, Grid::from_rows(vec![vec!['.', '.', 'X']]));
+}
+
+#[test]
+fn test_walk_into_obstacle() {
+    let mut map = Map {
+        map: Grid::from_rows(vec![vec!['.', '#', '<']]),
+        guard: Guard {
+            direction: Direction::West,
+            position: Point::new(2, 0),
+            directions_to_confirm_loop: Direction::CARDINAL.into_iter().collect(),
+            path_patrolled: false,
+        },
+    };
+
+    let can_walk = map.walk();
+    assert!(can_walk);
+    assert_eq!(map.guard.direction, Direction::North);
+    assert_eq!(map.guard.position, Point::new(2, 0));
+    assert_eq!(map.map, Grid::from_rows(vec![vec!['.', '#', '<']]));
+}
+
+#[test]
+fn test_loop_detection() {
+    let mut map = Map {
+        map: Grid::from_rows(vec![
+            vec!['#', '#', '#', '#'],
+            vec!['#', '.', '.', '#'],
+            vec!['#', '^', '.', '#'],
+            vec!['#', '#', '#', '#'],
+        ]),
+        guard: Guard {
+            direction: Direction::North,
+            position: Point::new(1, 2),
+            directions_to_confirm_loop: Direction::CARDINAL.into_iter().collect(),
+            path_patrolled: false,
+        },
+    };
+
+    let mut is_loop = false;
+
+    while map.walk() {
+        if map.guard.directions_to_confirm_loop.is_empty() {
+            is_loop = true;
+            break;
+        }
+    }
+
+    assert!(is_loop);
+}
+
+#[test]
+fn test_loop_detection_2() {
+    let mut map = Map {
+        map: Grid::from_rows(vec![
+            vec!['.', '#', '.', '.'],
+            vec!['.', '.', '#', '.'],
+            vec!['#', '^', '.', '.'],
+            vec!['.', '#', '.', '.'],
+        ]),
+        guard: Guard {
+            direction: Direction::North,
+            position: Point::new(1, 2),
+            directions_to_confirm_loop: Direction::CARDINAL.into_iter().collect(),
+            path_patrolled: false,
+        },
+    };
+
+    let mut is_loop = false;
+
+    while map.walk() {
+        if map.guard.directions_to_confirm_loop.is_empty() {
+            is_loop = true;
+            break;
+        }
+    }
+
+    assert!(is_loop);
+}
+
+#[test]
+fn test_map_unknown_character() {
+    let err = Map::new("..#\n.x^").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "day 6, line 2, column 2: unknown map character `x`"
+    );
+}
+
+#[test]
+fn test_map_without_guard() {
+    let err = Map::new("..#\n...").unwrap_err();
+    assert_eq!(err.to_string(), "day 6: no guard found in map");
+}
@@ -0,0 +1,343 @@
+use aoc_common::{parse::lines, Answer, ParseError, Solution, SolveError};
+
+const PART_1_OPERATIONS: [fn(u64) -> Operation; 2] = [Operation::Add, Operation::Multiply];
+const PART_2_OPERATIONS: [fn(u64) -> Operation; 3] =
+    [Operation::Add, Operation::Multiply, Operation::Concat];
+
+pub struct Day07;
+
+pub struct Equation {
+    target: u64,
+    operands: Vec<u64>,
+}
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Parsed = Vec<Equation>;
+
+    fn parse(input: &str) -> Result<Vec<Equation>, ParseError> {
+        lines(Self::DAY, input)
+            .map(|line| {
+                if let Some((target, operands)) = line.text.split_once(':') {
+                    let target = line.number::<u64>(target)?;
+                    let operands = operands
+                        .split_whitespace()
+                        .map(|num| line.number::<u64>(num))
+                        .collect::<Result<Vec<u64>, ParseError>>()?;
+                    if operands.is_empty() {
+                        return Err(line.error_at_end("expected at least one operand"));
+                    }
+                    Ok(Equation { target, operands })
+                } else {
+                    Err(line.error(line.text, "expected `target: operands`"))
+                }
+            })
+            .collect()
+    }
+
+    fn part1(equations: &Vec<Equation>) -> Result<Answer, SolveError> {
+        Ok(total_calibration(equations, &PART_1_OPERATIONS).into())
+    }
+
+    fn part2(equations: &Vec<Equation>) -> Result<Answer, SolveError> {
+        Ok(total_calibration(equations, &PART_2_OPERATIONS).into())
+    }
+}
+
+fn total_calibration(equations: &[Equation], operations: &[fn(u64) -> Operation]) -> u64 {
+    equations
+        .iter()
+        .filter(|equation| equation.is_solvable(operations))
+        .map(|equation| equation.target)
+        .sum::<u64>()
+}
+
+impl Equation {
+    fn is_solvable(&self, operations: &[fn(u64) -> Operation]) -> bool {
+        let (&acc, operands) = self.operands.split_first().unwrap();
+        generate_possible_combinations(operands, operations)
+            .iter()
+            .any(|combination| {
+                let sum = combination
+                    .iter()
+                    .fold(acc, |acc, operation| operation.resolve(acc));
+                sum == self.target
+            })
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+enum Operation {
+    Add(u64),
+    Multiply(u64),
+    Concat(u64),
+}
+
+impl Operation {
+    fn resolve(&self, acc: u64) -> u64 {
+        match self {
+            Operation::Add(x) => acc + x,
+            Operation::Multiply(x) => acc * x,
+            Operation::Concat(x) => {
+                let mut prefix = acc.to_string();
+                let suffix = x.to_string();
+                prefix.push_str(suffix.as_str());
+                prefix.parse::<u64>().unwrap()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[derive(PartialEq, Eq, Debug)]
+struct UndoOperation {
+    operation: Operation,
+    val: u64,
+}
+
+#[cfg(test)]
+impl UndoOperation {
+    fn undo(self) -> u64 {
+        match self.operation {
+            Operation::Add(x) => self.val - x,
+            Operation::Multiply(x) => self.val / x,
+            Operation::Concat(x) => {
+                self.val / 10_u64.pow(u32::try_from(x.to_string().len()).unwrap())
+            }
+        }
+    }
+}
+
+// The ith combination picks the operation for the jth operand from the jth
+// digit of i, written in base `operations.len()`.
+fn generate_possible_combinations(
+    operands: &[u64],
+    operations: &[fn(u64) -> Operation],
+) -> Vec<Vec<Operation>> {
+    let base = operations.len();
+    (0..base.pow(u32::try_from(operands.len()).unwrap()))
+        .map(|i| {
+            let mut i = i;
+            operands
+                .iter()
+                .map(|&num| {
+                    let operation = operations[i % base](num);
+                    i /= base;
+                    operation
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn test_add() {
+    let operation = Operation::Add(5);
+    let result = operation.resolve(2);
+    let expected = 7;
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_multiply() {
+    let operation = Operation::Multiply(5);
+    let result = operation.resolve(2);
+    let expected = 10;
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_undo_add() {
+    let undo_operation = UndoOperation {
+        operation: Operation::Add(5),
+        val: 7,
+    };
+    let result = undo_operation.undo();
+
+    assert_eq!(result, 2);
+}
+
+#[test]
+fn test_undo_multiply() {
+    let undo_operation = UndoOperation {
+        operation: Operation::Multiply(5),
+        val: 10,
+    };
+    let result = undo_operation.undo();
+
+    assert_eq!(result, 2);
+}
+
+#[test]
+fn test_undo_concat() {
+    let undo_operation = UndoOperation {
+        operation: Operation::Concat(45),
+        val: 12345,
+    };
+    let result = undo_operation.undo();
+
+    assert_eq!(result, 123);
+}
+
+#[test]
+fn test_generate_possible_combinations() {
+    let operands = vec![1, 2, 3];
+    let result: Vec<Vec<Operation>> = generate_possible_combinations(&operands, &PART_1_OPERATIONS);
+    let expected = vec![
+        vec![Operation::Add(1), Operation::Add(2), Operation::Add(3)],
+        vec![Operation::Multiply(1), Operation::Add(2), Operation::Add(3)],
+        vec![Operation::Add(1), Operation::Multiply(2), Operation::Add(3)],
+        vec![
+            Operation::Multiply(1),
+            Operation::Multiply(2),
+            Operation::Add(3),
+        ],
+        vec![Operation::Add(1), Operation::Add(2), Operation::Multiply(3)],
+        vec![
+            Operation::Multiply(1),
+            Operation::Add(2),
+            Operation::Multiply(3),
+        ],
+        vec![
+            Operation::Add(1),
+            Operation::Multiply(2),
+            Operation::Multiply(3),
+        ],
+        vec![
+            Operation::Multiply(1),
+            Operation::Multiply(2),
+            Operation::Multiply(3),
+        ],
+    ];
+    assert_eq!(result, expected);
+}
+
+#[test]
+#[allow(clippy::too_many_lines)]
+fn test_generate_possible_combinations_with_concat() {
+    let operands = vec![1, 2, 3];
+    let result: Vec<Vec<Operation>> = generate_possible_combinations(&operands, &PART_2_OPERATIONS);
+    let expected = vec![
+        vec![Operation::Add(1), Operation::Add(2), Operation::Add(3)],
+        vec![Operation::Multiply(1), Operation::Add(2), Operation::Add(3)],
+        vec![Operation::Concat(1), Operation::Add(2), Operation::Add(3)],
+        vec![Operation::Add(1), Operation::Multiply(2), Operation::Add(3)],
+        vec![
+            Operation::Multiply(1),
+            Operation::Multiply(2),
+            Operation::Add(3),
+        ],
+        vec![
+            Operation::Concat(1),
+            Operation::Multiply(2),
+            Operation::Add(3),
+        ],
+        vec![Operation::Add(1), Operation::Concat(2), Operation::Add(3)],
+        vec![
+            Operation::Multiply(1),
+            Operation::Concat(2),
+            Operation::Add(3),
+        ],
+        vec![
+            Operation::Concat(1),
+            Operation::Concat(2),
+            Operation::Add(3),
+        ],
+        vec![Operation::Add(1), Operation::Add(2), Operation::Multiply(3)],
+        vec![
+            Operation::Multiply(1),
+            Operation::Add(2),
+            Operation::Multiply(3),
+        ],
+        vec![
+            Operation::Concat(1),
+            Operation::Add(2),
+            Operation::Multiply(3),
+        ],
+        vec![
+            Operation::Add(1),
+            Operation::Multiply(2),
+            Operation::Multiply(3),
+        ],
+        vec![
+            Operation::Multiply(1),
+            Operation::Multiply(2),
+            Operation::Multiply(3),
+        ],
+        vec![
+            Operation::Concat(1),
+            Operation::Multiply(2),
+            Operation::Multiply(3),
+        ],
+        vec![
+            Operation::Add(1),
+            Operation::Concat(2),
+            Operation::Multiply(3),
+        ],
+        vec![
+            Operation::Multiply(1),
+            Operation::Concat(2),
+            Operation::Multiply(3),
+        ],
+        vec![
+            Operation::Concat(1),
+            Operation::Concat(2),
+            Operation::Multiply(3),
+        ],
+        vec![Operation::Add(1), Operation::Add(2), Operation::Concat(3)],
+        vec![
+            Operation::Multiply(1),
+            Operation::Add(2),
+            Operation::Concat(3),
+        ],
+        vec![
+            Operation::Concat(1),
+            Operation::Add(2),
+            Operation::Concat(3),
+        ],
+        vec![
+            Operation::Add(1),
+            Operation::Multiply(2),
+            Operation::Concat(3),
+        ],
+        vec![
+            Operation::Multiply(1),
+            Operation::Multiply(2),
+            Operation::Concat(3),
+        ],
+        vec![
+            Operation::Concat(1),
+            Operation::Multiply(2),
+            Operation::Concat(3),
+        ],
+        vec![
+            Operation::Add(1),
+            Operation::Concat(2),
+            Operation::Concat(3),
+        ],
+        vec![
+            Operation::Multiply(1),
+            Operation::Concat(2),
+            Operation::Concat(3),
+        ],
+        vec![
+            Operation::Concat(1),
+            Operation::Concat(2),
+            Operation::Concat(3),
+        ],
+    ];
+    assert_eq!(result.len(), 27);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_parse_missing_colon() {
+    let err = Day07::parse("190: 10 19\n3267 81 40 27").err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "day 7, line 2, column 1: expected `target: operands` `3267 81 40 27`"
+    );
+}
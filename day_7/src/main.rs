@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day07::Day07;
+
+fn main() -> ExitCode {
+    aoc_common::runner::main::<Day07>()
+}
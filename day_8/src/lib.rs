@@ -0,0 +1,327 @@
+use std::collections::HashMap;
+
+use aoc_common::{Answer, Grid, ParseError, Point, Solution, SolveError};
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct Map {
+    map: Grid<char>,
+    antenna_locs: HashMap<char, Vec<Point>>,
+}
+
+impl Map {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let mut antenna_locs = HashMap::new();
+        let map = Grid::parse(Day08::DAY, input, |line, x, c| {
+            if c.is_ascii_alphanumeric() {
+                antenna_locs
+                    .entry(c)
+                    .or_insert_with(Vec::new)
+                    .push(Point::new(x, line.number - 1));
+            } else if c != '.' {
+                return Err(line.error_at(x, &c.to_string(), "unknown map character"));
+            }
+            Ok(c)
+        })?;
+        Ok(Self { map, antenna_locs })
+    }
+
+    fn fill_antinodes(&self) -> Map {
+        let mut filled_map = self.map.clone();
+        self.antenna_locs.values().for_each(|coordinates| {
+            (0..coordinates.len()).for_each(|idx_1| {
+                (idx_1 + 1..coordinates.len()).for_each(|idx_2| {
+                    let antenna_1 = coordinates[idx_1];
+                    let antenna_2 = coordinates[idx_2];
+                    let step = antenna_1 - antenna_2;
+
+                    // Each antinode sits as far beyond one antenna as the other antenna is
+                    // behind it.
+                    let antinode_1_location = self.map.offset(antenna_1, step);
+                    let antinode_2_location = self.map.offset(antenna_2, -step);
+
+                    for location in [antinode_1_location, antinode_2_location]
+                        .into_iter()
+                        .flatten()
+                    {
+                        filled_map[location] = '#';
+                    }
+                });
+            });
+        });
+        Map {
+            map: filled_map,
+            antenna_locs: self.antenna_locs.clone(),
+        }
+    }
+
+    fn fill_harmonic_antinodes(&self) -> Map {
+        let mut filled_map = self.map.clone();
+        self.antenna_locs.values().for_each(|coordinates| {
+            (0..coordinates.len()).for_each(|idx_1| {
+                (idx_1 + 1..coordinates.len()).for_each(|idx_2| {
+                    let Point { x: x1, y: y1 } = coordinates[idx_1];
+                    let Point { x: x2, y: y2 } = coordinates[idx_2];
+
+                    let y1 = u16::try_from(y1).unwrap();
+                    let y2 = u16::try_from(y2).unwrap();
+                    let x1 = u16::try_from(x1).unwrap();
+                    let x2 = u16::try_from(x2).unwrap();
+
+                    let gradient =
+                        { (f64::from(y1) - f64::from(y2)) / (f64::from(x1) - f64::from(x2)) };
+
+                    let constant = f64::from(y1) - (gradient * f64::from(x1));
+
+                    self.map.points().for_each(|point| {
+                        // Check if it lies on the line
+                        if gradient.is_infinite() {
+                            if point.x == usize::from(x1) {
+                                filled_map[point] = '#';
+                            }
+                        } else {
+                            let x_u16 = u16::try_from(point.x).unwrap();
+                            let expected_y = (gradient * f64::from(x_u16)) + constant;
+                            let y_u16 = u16::try_from(point.y).unwrap();
+
+                            if (f64::from(y_u16) - expected_y).abs() < 0.01 {
+                                filled_map[point] = '#';
+                            };
+                        }
+                    });
+                });
+            });
+        });
+        Map {
+            map: filled_map,
+            antenna_locs: self.antenna_locs.clone(),
+        }
+    }
+
+    fn count_antinodes(&self) -> usize {
+        self.map.iter().filter(|(_, &c)| c == '#').count()
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Parsed = Map;
+
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        Map::new(input)
+    }
+
+    fn part1(map: &Map) -> Result<Answer, SolveError> {
+        Ok(map.fill_antinodes().count_antinodes().into())
+    }
+
+    fn part2(map: &Map) -> Result<Answer, SolveError> {
+        Ok(map.fill_harmonic_antinodes().count_antinodes().into())
+    }
+}
+
+#[test]
+fn test_map_creation() {
+    let input = ".aa.\n....\n....";
+    let map = Map::new(input).unwrap();
+    let expected = Map {
+        map: Grid::from_rows(vec![
+            vec!['.', 'a', 'a', '.'],
+            vec!['.', '.', '.', '.'],
+            vec!['.', '.', '.', '.'],
+        ]),
+        antenna_locs: [('a', vec![Point::new(1, 0), Point::new(2, 0)])]
+            .into_iter()
+            .collect(),
+    };
+
+    assert_eq!(map, expected);
+}
+
+#[test]
+fn test_antinode_creation_horizontal() {
+    let input = ".aa.\n....\n....";
+    let map = Map::new(input).unwrap();
+    let filled_map = map.fill_antinodes();
+    let expected = Map {
+        map: Grid::from_rows(vec![
+            vec!['#', 'a', 'a', '#'],
+            vec!['.', '.', '.', '.'],
+            vec!['.', '.', '.', '.'],
+        ]),
+        antenna_locs: [('a', vec![Point::new(1, 0), Point::new(2, 0)])]
+            .into_iter()
+            .collect(),
+    };
+
+    assert_eq!(filled_map, expected);
+}
+
+#[test]
+fn test_antinode_creation_vertical() {
+    let input = ".a..\n.a..\n....";
+    let map = Map::new(input).unwrap();
+    let filled_map = map.fill_antinodes();
+    let expected = Map {
+        map: Grid::from_rows(vec![
+            vec!['.', 'a', '.', '.'],
+            vec!['.', 'a', '.', '.'],
+            vec!['.', '#', '.', '.'],
+        ]),
+        antenna_locs: [('a', vec![Point::new(1, 0), Point::new(1, 1)])]
+            .into_iter()
+            .collect(),
+    };
+
+    assert_eq!(filled_map, expected);
+}
+
+#[test]
+fn test_antinode_creation_diagonal() {
+    let input = ".a..\n..a.\n....";
+    let map = Map::new(input).unwrap();
+    let filled_map = map.fill_antinodes();
+    let expected = Map {
+        map: Grid::from_rows(vec![
+            vec!['.', 'a', '.', '.'],
+            vec!['.', '.', 'a', '.'],
+            vec!['.', '.', '.', '#'],
+        ]),
+        antenna_locs: [('a', vec![Point::new(1, 0), Point::new(2, 1)])]
+            .into_iter()
+            .collect(),
+    };
+
+    assert_eq!(filled_map, expected);
+}
+
+#[test]
+fn test_antinode_creation_horizontal_with_harmonics() {
+    let input = ".aa..\n.....\n.....";
+    let map = Map::new(input).unwrap();
+    let filled_map = map.fill_harmonic_antinodes();
+    let expected = Map {
+        map: Grid::from_rows(vec![
+            vec!['#', '#', '#', '#', '#'],
+            vec!['.', '.', '.', '.', '.'],
+            vec!['.', '.', '.', '.', '.'],
+        ]),
+        antenna_locs: [('a', vec![Point::new(1, 0), Point::new(2, 0)])]
+            .into_iter()
+            .collect(),
+    };
+
+    assert_eq!(filled_map, expected);
+}
+
+#[test]
+fn test_antinode_creation_vertical_with_harmonics() {
+    let input = ".a..\n.a..\n....\n....";
+    let map = Map::new(input).unwrap();
+    let filled_map = map.fill_harmonic_antinodes();
+    let expected = Map {
+        map: Grid::from_rows(vec![
+            vec!['.', '#', '.', '.'],
+            vec!['.', '#', '.', '.'],
+            vec!['.', '#', '.', '.'],
+            vec!['.', '#', '.', '.'],
+        ]),
+        antenna_locs: [('a', vec![Point::new(1, 0), Point::new(1, 1)])]
+            .into_iter()
+            .collect(),
+    };
+
+    assert_eq!(filled_map, expected);
+}
+
+#[test]
+fn test_antinode_creation_diagonal_with_harmonics() {
+    let input = ".a...\n..a..\n.....\n.....";
+    let map = Map::new(input).unwrap();
+    let filled_map = map.fill_harmonic_antinodes();
+    let expected = Map {
+        map: Grid::from_rows(vec![
+            vec!['.', '#', '.', '.', '.'],
+            vec!['.', '.', '#', '.', '.'],
+            vec!['.', '.', '.', '#', '.'],
+            vec!['.', '.', '.', '.', '#'],
+        ]),
+        antenna_locs: [('a', vec![Point::new(1, 0), Point::new(2, 1)])]
+            .into_iter()
+            .collect(),
+    };
+
+    assert_eq!(filled_map, expected);
+}
+
+#[test]
+fn test_antinode_creation_diagonal_inbetween_with_harmonics() {
+    let input = ".....\n.a...\n.....\n...a.\n.....";
+    let map = Map::new(input).unwrap();
+    let filled_map = map.fill_harmonic_antinodes();
+    let expected = Map {
+        map: Grid::from_rows(vec![
+            vec!['#', '.', '.', '.', '.'],
+            vec!['.', '#', '.', '.', '.'],
+            vec!['.', '.', '#', '.', '.'],
+            vec!['.', '.', '.', '#', '.'],
+            vec!['.', '.', '.', '.', '#'],
+        ]),
+        antenna_locs: [('a', vec![Point::new(1, 1), Point::new(3, 3)])]
+            .into_iter()
+            .collect(),
+    };
+
+    assert_eq!(filled_map, expected);
+}
+
+#[test]
+fn test_antinode_creation_horizontal_inbetween_with_harmonics() {
+    let input = ".....\n.....\n.a.a.\n.....\n.....";
+    let map = Map::new(input).unwrap();
+    let filled_map = map.fill_harmonic_antinodes();
+    let expected = Map {
+        map: Grid::from_rows(vec![
+            vec!['.', '.', '.', '.', '.'],
+            vec!['.', '.', '.', '.', '.'],
+            vec!['#', '#', '#', '#', '#'],
+            vec!['.', '.', '.', '.', '.'],
+            vec!['.', '.', '.', '.', '.'],
+        ]),
+        antenna_locs: [('a', vec![Point::new(1, 2), Point::new(3, 2)])]
+            .into_iter()
+            .collect(),
+    };
+
+    assert_eq!(filled_map, expected);
+}
+
+#[test]
+fn test_antinode_single_antenna_with_harmonics() {
+    let input = ".....\n.....\n..a..\n.....\n.....";
+    let map = Map::new(input).unwrap();
+    let filled_map = map.fill_harmonic_antinodes();
+    let expected = Map {
+        map: Grid::from_rows(vec![
+            vec!['.', '.', '.', '.', '.'],
+            vec!['.', '.', '.', '.', '.'],
+            vec!['.', '.', 'a', '.', '.'],
+            vec!['.', '.', '.', '.', '.'],
+            vec!['.', '.', '.', '.', '.'],
+        ]),
+        antenna_locs: [('a', vec![Point::new(2, 2)])].into_iter().collect(),
+    };
+
+    assert_eq!(filled_map, expected);
+}
+
+#[test]
+fn test_map_unknown_character() {
+    let err = Map::new(".a..\n..?.").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "day 8, line 2, column 3: unknown map character `?`"
+    );
+}
@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day08::Day08;
+
+fn main() -> ExitCode {
+    aoc_common::runner::main::<Day08>()
+}
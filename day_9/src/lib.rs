@@ -0,0 +1,266 @@
+use aoc_common::{parse::lines, Answer, ParseError, Solution, SolveError};
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+struct Block {
+    val: Option<u32>,
+    size: u8,
+}
+
+impl Block {
+    fn new(size: u8, val: Option<u32>) -> Self {
+        Self { size, val }
+    }
+}
+
+fn shift_blocks(blocks: &mut Vec<Block>) {
+    let Some(mut right_ptr) = blocks.len().checked_sub(1) else {
+        return;
+    };
+
+    loop {
+        // Advance right_ptr until stored space
+        while right_ptr > 0 && blocks[right_ptr].val.is_none() {
+            right_ptr -= 1;
+        }
+
+        let block_to_shift = blocks[right_ptr].clone();
+        let required_size = block_to_shift.size;
+        let shifted_val = block_to_shift.val;
+        {
+            // Searchable blocks
+            let searchable_blocks = blocks.get_mut(0..right_ptr).unwrap();
+
+            if searchable_blocks.is_empty() {
+                break;
+            }
+
+            // Advance left_ptr until free space
+            if let Some((idx, viable_block)) = searchable_blocks
+                .iter_mut()
+                .enumerate()
+                .find(|(_, block)| block.val.is_none() && block.size >= required_size)
+            {
+                if viable_block.size == required_size {
+                    *viable_block = Block::new(required_size, shifted_val);
+                    *blocks.get_mut(right_ptr).unwrap() = Block::new(required_size, None);
+                } else {
+                    *viable_block = Block::new(viable_block.size - required_size, None);
+                    blocks.insert(idx, Block::new(required_size, shifted_val));
+                    *blocks.get_mut(right_ptr + 1).unwrap() = Block::new(required_size, None);
+                }
+            } else {
+                right_ptr -= 1;
+            }
+        }
+    }
+}
+
+// Files and free space alternate in the disk map, starting with file 0.
+fn disk_blocks(disk_map: &[u8]) -> Vec<Block> {
+    disk_map
+        .iter()
+        .enumerate()
+        .map(|(idx, &size)| {
+            if idx % 2 == 0 {
+                let val = u32::try_from(idx / 2).unwrap();
+                Block::new(size, Some(val))
+            } else {
+                Block::new(size, None)
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+fn render_blocks(blocks: &[Block]) -> String {
+    blocks
+        .iter()
+        .map(|block| {
+            let c = block.val.map_or(".".to_string(), |val| val.to_string());
+            c.repeat(usize::from(block.size))
+        })
+        .collect()
+}
+
+fn create_block(size: usize, val: String) -> Vec<String> {
+    (0..size).map(|_| val.clone()).collect()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Parsed = Vec<u8>;
+
+    fn parse(input: &str) -> Result<Vec<u8>, ParseError> {
+        let disk_map: Vec<u8> = lines(Self::DAY, input.trim_end())
+            .flat_map(|line| {
+                line.text
+                    .char_indices()
+                    .map(move |(idx, c)| line.digit(idx, c).map(|size| size.try_into().unwrap()))
+            })
+            .collect::<Result<_, _>>()?;
+        if disk_map.is_empty() {
+            return Err(ParseError::whole_input(Self::DAY, "empty disk map"));
+        }
+        Ok(disk_map)
+    }
+
+    fn part1(disk_map: &Vec<u8>) -> Result<Answer, SolveError> {
+        let mut blocks: Vec<String> = disk_map
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, &size)| {
+                if idx % 2 == 0 {
+                    let val = u32::try_from(idx / 2).unwrap();
+                    create_block(usize::from(size), val.to_string())
+                } else {
+                    create_block(usize::from(size), ".".to_string())
+                }
+            })
+            .collect();
+
+        let mut left_ptr = 0;
+        let mut right_ptr = blocks.len().saturating_sub(1);
+
+        loop {
+            // Advance left_ptr until free space
+            while left_ptr < right_ptr && blocks[left_ptr] != "." {
+                left_ptr += 1;
+            }
+
+            // Advance right_ptr until stored space
+            while left_ptr < right_ptr && blocks[right_ptr] == "." {
+                right_ptr -= 1;
+            }
+
+            if left_ptr >= right_ptr {
+                break;
+            }
+
+            // Swap stored and free space
+            blocks.swap(left_ptr, right_ptr);
+        }
+
+        Ok(blocks
+            .iter()
+            .enumerate()
+            .map(|(idx, s)| {
+                if s != "." {
+                    let val = s.parse::<usize>().unwrap();
+                    idx * val
+                } else {
+                    0
+                }
+            })
+            .sum::<usize>()
+            .into())
+    }
+
+    fn part2(disk_map: &Vec<u8>) -> Result<Answer, SolveError> {
+        let mut blocks = disk_blocks(disk_map);
+
+        shift_blocks(&mut blocks);
+
+        let (_, result) = blocks.iter().fold((0, 0), |(offset, acc), block| {
+            if let Some(val) = block.val {
+                let checksum = (0..block.size)
+                    .map(|idx| (u64::from(idx) + offset) * u64::from(val))
+                    .sum::<u64>();
+                (offset + u64::from(block.size), acc + checksum)
+            } else {
+                (offset + u64::from(block.size), acc)
+            }
+        });
+
+        Ok(result.into())
+    }
+}
+
+#[test]
+fn test_create_string_block() {
+    let block = create_block(3, "9".to_string());
+    assert_eq!(block, vec!["9", "9", "9"]);
+}
+
+#[test]
+fn test_create_empty_space() {
+    let block = create_block(5, ".".to_string());
+    assert_eq!(block, vec![".", ".", ".", ".", "."]);
+}
+
+#[test]
+fn test_create_block() {
+    let block = Block::new(3, Some(9));
+    assert_eq!(
+        block,
+        Block {
+            size: 3,
+            val: Some(9)
+        }
+    );
+}
+
+#[test]
+fn test_shift_blocks() {
+    let mut blocks = vec![
+        Block::new(2, Some(0)),
+        Block::new(3, None),
+        Block::new(3, Some(1)),
+    ];
+    shift_blocks(&mut blocks);
+
+    assert_eq!(
+        blocks,
+        vec![
+            Block::new(2, Some(0)),
+            Block::new(3, Some(1)),
+            Block::new(3, None)
+        ]
+    );
+}
+
+#[test]
+fn test_shift_example_blocks() {
+    let disk_map = Day09::parse("2333133121414131402").unwrap();
+    let mut blocks = disk_blocks(&disk_map);
+    shift_blocks(&mut blocks);
+
+    assert_eq!(
+        render_blocks(&blocks),
+        "00992111777.44.333....5555.6666.....8888.."
+    );
+}
+
+#[test]
+fn test_parse_invalid_digit() {
+    let err = Day09::parse("2333x33").err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "day 9, line 1, column 5: expected a digit `x`"
+    );
+}
+
+#[test]
+fn test_parse_empty() {
+    let err = Day09::parse("\n").err().unwrap();
+    assert_eq!(err.to_string(), "day 9: empty disk map");
+}
+
+#[test]
+fn test_no_free_space() {
+    for (input, checksum) in [("1", 0), ("9", 0), ("10101", 5), ("0", 0)] {
+        let disk_map = Day09::parse(input).unwrap();
+        assert_eq!(
+            Day09::part1(&disk_map),
+            Ok(Answer::Number(checksum)),
+            "{input}"
+        );
+        assert_eq!(
+            Day09::part2(&disk_map),
+            Ok(Answer::Number(checksum)),
+            "{input}"
+        );
+    }
+}
@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day09::Day09;
+
+fn main() -> ExitCode {
+    aoc_common::runner::main::<Day09>()
+}